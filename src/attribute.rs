@@ -1,100 +1,753 @@
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
 use crate::err::Error;
 
 // Different attributes that a field can have.
 pub enum FieldAttr {
-    // Represents the repeated setter attribute: `#[builder(each = "name")]`
-    // `String` will be the name specified by the user.
+    // Represents the repeated setter attribute: `#[builder(each = "name")]` or `#[builder(each)]`
+    // `String` will be the name specified by the user, or the singular of the name of the field.
     Repeat(String),
 
+    // Represents the `#[builder(keep_collection_setter)]` attribute.
+    // Keeps the setter of the whole collection when the repeated setter has the same name.
+    KeepCollectionSetter,
+
+    // Represents the `#[builder(on_push = hook)]` attribute.
+    // `Value` is a closure or function that the repeated setter calls with a reference to each pushed item.
+    OnPush(Value),
+
+    // Represents the setter name attribute: `#[builder(name = "name")]` or `#[builder(name = [name, alias])]`
+    // `Vec<String>` will be the names specified by the user. The first one names the setter, the others its aliases.
+    Name(Vec<String>),
+
+    // Represents the setter alias attribute: `#[builder(alias = "alias")]` or `#[builder(alias = [alias, other])]`
+    // `Vec<String>` will be extra names of the setter, which keeps its own name unlike with `name`.
+    Alias(Vec<String>),
+
+    // Represents the bulk repeated setter attribute: `#[builder(extend = "name")]`
+    // `String` will be the name specified by the user.
+    Extend(String),
+
     // Represents the default attribute:
-    // If the `Option<syn::Lit>` is `None`: `#[builder(default)]`
-    // If the `Option<syn::Lit>` is `Some`: `#[builder(default = lit)]`
-    Default(Option<syn::Lit>),
+    // If the `Option<Value>` is `None`: `#[builder(default)]`
+    // If the `Option<Value>` is `Some`: `#[builder(default = value)]`
+    Default(Option<Value>),
+
+    // Represents the `#[builder(value = value)]` attribute.
+    // The field is always set to `Value` and has no setter, as if it was skipped.
+    Value(Value),
+
+    // Represents the `#[builder(try_default = value)]` attribute.
+    // `Value` evaluates to a `Result` of the default value, so `builder()` returns a `Result` too.
+    TryDefault(Value),
+
+    // Represents the `#[builder(try_value = value)]` attribute.
+    // Like `Value` but fallible like `TryDefault`, so the field has no setter and `builder()` returns a `Result`.
+    TryValue(Value),
+
+    // Represents the `#[builder(default_from = |s| ...)]` attribute.
+    // `Value` is a closure or function computing the default value from the built struct.
+    DefaultFrom(Value),
+
+    // Represents the `#[builder(check = check)]` attribute.
+    // `Value` is a closure or function that validates a reference to the value passed to the setter.
+    Check(Value),
+
+    // Represents the `#[builder(check_with = check)]` attribute.
+    // Like `Check`, but `Value` also takes a reference to the builder, so it can compare the value to the ones set before.
+    CheckWith(Value),
+
+    // Represents the `#[builder(check_error = "Type")]` attribute.
+    // `syn::Type` will be the error type returned by the `check` of the field.
+    CheckError(Box<syn::Type>),
+
+    // Represents the `#[builder(once_cell)]` attribute.
+    OnceCell,
+
+    // Represents the `#[builder(once)]` attribute.
+    // The setter of an optional field can only be called once, which is tracked in the type of the builder.
+    Once,
+
+    // Represents the `#[builder(flatten)]` attribute.
+    // The type of the field derives `Builder`, and the field gets a setter that configures its builder using a closure.
+    Flatten,
+
+    // Represents the `#[builder(each_builder)]` attribute.
+    // The items of a field with a repeated setter derive `Builder`, and the field gets a repeated setter
+    // that configures the builder of an item using a closure.
+    EachBuilder,
+
+    // Represents the `#[builder(per_index)]` attribute.
+    // Each element of an array field gets its own setter.
+    PerIndex,
+
+    // Represents the `#[builder(setter = wrap)]` attribute.
+    // The setter takes the inner type of a `Box`, `Rc`, or `Arc` and wraps it in the pointer.
+    Wrap,
+
+    // Represents the `#[builder(into)]` attribute.
+    // The setter takes any value that converts into the type of the field.
+    Into,
+
+    // Represents the `#[builder(deprecated = "note")]` attribute on a field.
+    // `String` will be the note of the deprecation of the setters of the field.
+    Deprecated(String),
+
+    // Represents the `#[builder(vis = "pub(crate)")]` attribute.
+    // `syn::Visibility` will be the visibility of the setters of the field.
+    Vis(Box<syn::Visibility>),
+
+    // Represents the `#[builder(no_into)]` attribute.
+    // The setter takes the type of the field as is, even with `#[builder(into)]` on the struct.
+    NoInto,
+
+    // Represents the `#[builder(to_string)]` attribute.
+    // The setter takes any value that implements `Display` and stores its string representation.
+    ToString,
+
+    // Represents the `#[builder(setter = set_if_some)]` attribute.
+    // The setter takes an `Option` and only sets the value if it's `Some`.
+    SetIfSome,
+
+    // Represents the `#[builder(secret)]` attribute.
+    // The value of the field is redacted in the `Debug` generated by `#[builder(struct_debug)]`.
+    Secret,
 
     // Represents the `#[builder(skip)]` attribute.
     Skip,
+
+    // Represents the `#[builder(required)]` attribute.
+    // An `Option` field is required by the builder, and its setter takes the inner type.
+    Required,
+
+    // Represents a `#[cfg(...)]` attribute of the field.
+    // The builder must only use the field when it's compiled in.
+    Cfg(syn::Attribute),
+}
+
+// Different attributes that a struct can have.
+pub enum StructAttr {
+    // Represents the derive attribute: `#[builder(derive(Clone))]`
+    // `Vec<syn::Ident>` will be the traits that the builder should implement.
+    Derive(Vec<syn::Ident>),
+
+    // Represents the `#[builder(no_std)]` attribute.
+    NoStd,
+
+    // Represents the `#[builder(test_only)]` attribute.
+    TestOnly,
+
+    // Represents the `#[builder(trace)]` attribute.
+    Trace,
+
+    // Represents the `#[builder(storage = maybe_uninit)]` and `#[builder(storage = option)]` attributes.
+    // `bool` will be whether the required fields are stored in `MaybeUninit` instead of `Option`.
+    Storage(bool),
+
+    // Represents the `#[builder(mode = runtime)]` and `#[builder(mode = typestate)]` attributes.
+    // `bool` will be whether the builder checks the required fields at runtime instead of tracking them in its type.
+    Mode(bool),
+
+    // Represents the `#[builder(struct_debug)]` attribute.
+    // The struct itself gets a `Debug` implementation.
+    StructDebug,
+
+    // Represents the `#[builder(into_builder)]` attribute.
+    // The struct can be converted back into a builder which is ready to build.
+    IntoBuilder,
+
+    // Represents the `#[builder(both_modes)]` attribute.
+    BothModes,
+
+    // Represents the `#[builder(into)]` attribute on the struct.
+    // Every setter takes any value that converts into the type of its field.
+    Into,
+
+    // Represents the `#[builder(partial)]` attribute.
+    // The builder gets a `partial` function returning a snapshot of the values set so far.
+    Partial,
+
+    // Represents the `#[builder(setters_trait)]` attribute.
+    // The setters that don't change the state of the builder are also provided through a `{Struct}Setters` trait.
+    SettersTrait,
+
+    // Represents the `#[builder(default)]` attribute on the struct.
+    // The struct implements `Default` even with required fields, which get their `Default` value.
+    Default,
+
+    // Represents the `#[builder(from_pairs)]` attribute.
+    // The struct gets a `from_pairs` function setting its fields by their names at runtime.
+    FromPairs,
+
+    // Represents the `#[builder(const)]` attribute.
+    // The setters that only assign their argument are `const fn`.
+    Const,
+
+    // Represents the `#[builder(str_setters)]` attribute.
+    // The setters of `String` values take a `&str` instead.
+    StrSetters,
+
+    // Represents the `#[builder(state_enum)]` attribute.
+    // The builder gets a `{Builder}State` enum with a variant for each of its states.
+    StateEnum,
+
+    // Represents the `#[builder(feature = "name")]` attribute.
+    // `String` will be the name of the feature that the builder is generated behind.
+    Feature(String),
+
+    // Represents the `#[builder(deprecated = "note")]` attribute.
+    // `String` will be the note of the deprecation of `builder()`.
+    Deprecated(String),
+
+    // Represents the `#[builder(repair = repair)]` attribute.
+    // `Value` is a closure or function that turns the built struct into a valid one.
+    Repair(Value),
+
+    // Represents the `#[builder(setter_prefix = "prefix")]` and `#[builder(setter_prefix = type)]` attributes.
+    // `String` will be the prefix of the setters specified by the user, or `None` to use the name of the struct.
+    SetterPrefix(Option<String>),
+
+    // Represents the `#[builder(rename_all = "camelCase")]` attribute.
+    // `Case` will be the case of the setter names, which serde calls by names like "camelCase".
+    RenameAll(convert_case::Case),
+
+    // Represents the `#[builder(error = "Type")]` attribute.
+    // `syn::Type` will be the error type of `builder()` when a field has a `try_default`.
+    Error(Box<syn::Type>),
+}
+
+// The value of a name value attribute like `#[builder(default = value)]`.
+//
+// `syn::Meta` only accepts literals as values, but we want to accept paths and arbitrary expressions too,
+// e.g. `#[builder(default = make_default)]` or `#[builder(default = 2 * SIZE)]`.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Lit(syn::Lit),
+    Path(syn::Path),
+    Expr(Box<syn::Expr>),
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Keywords are not expressions, but `#[builder(setter_prefix = type)]` takes one.
+        if input.peek(syn::Token![type]) {
+            let keyword: syn::Ident = input.call(syn::ext::IdentExt::parse_any)?;
+
+            return Ok(Value::Path(keyword.into()));
+        }
+
+        match input.parse()? {
+            syn::Expr::Lit(syn::ExprLit { attrs, lit }) if attrs.is_empty() => Ok(Value::Lit(lit)),
+            syn::Expr::Path(syn::ExprPath {
+                attrs,
+                qself: None,
+                path,
+            }) if attrs.is_empty() => Ok(Value::Path(path)),
+            expr => Ok(Value::Expr(Box::new(expr))),
+        }
+    }
+}
+
+impl ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Value::Lit(lit) => lit.to_tokens(tokens),
+            Value::Path(path) => path.to_tokens(tokens),
+            Value::Expr(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+// Mirrors `syn::Meta` but uses `Value` for the value of name value attributes.
+#[derive(Debug)]
+pub enum Meta {
+    // `#[builder(skip)]`
+    Path(syn::Ident),
+
+    // `#[builder(default = value)]`
+    NameValue(syn::Ident, syn::Token![=], Value),
+
+    // `#[builder(derive(Clone))]`
+    List(
+        syn::Ident,
+        syn::token::Paren,
+        Punctuated<NestedMeta, syn::Token![,]>,
+    ),
+}
+
+impl Parse for Meta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Keywords are not identifiers, but `#[builder(const)]` is named after one.
+        let name = input.call(syn::ext::IdentExt::parse_any)?;
+
+        if input.peek(syn::Token![=]) {
+            Ok(Meta::NameValue(name, input.parse()?, input.parse()?))
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            let paren = syn::parenthesized!(content in input);
+
+            Ok(Meta::List(
+                name,
+                paren,
+                content.parse_terminated(NestedMeta::parse)?,
+            ))
+        } else {
+            Ok(Meta::Path(name))
+        }
+    }
+}
+
+impl ToTokens for Meta {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Meta::Path(name) => name.to_tokens(tokens),
+            Meta::NameValue(name, eq, value) => {
+                name.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            Meta::List(name, paren, nested) => {
+                name.to_tokens(tokens);
+                paren.surround(tokens, |tokens| nested.to_tokens(tokens));
+            }
+        }
+    }
+}
+
+// Mirrors `syn::NestedMeta` but uses our own `Meta`.
+#[derive(Debug)]
+pub enum NestedMeta {
+    Meta(Meta),
+    Lit(syn::Lit),
+}
+
+impl Parse for NestedMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Lit) {
+            Ok(NestedMeta::Lit(input.parse()?))
+        } else {
+            Ok(NestedMeta::Meta(input.parse()?))
+        }
+    }
+}
+
+impl ToTokens for NestedMeta {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            NestedMeta::Meta(meta) => meta.to_tokens(tokens),
+            NestedMeta::Lit(lit) => lit.to_tokens(tokens),
+        }
+    }
 }
 
-fn parse_attr(
-    nested: &syn::punctuated::Punctuated<syn::NestedMeta, syn::token::Comma>,
-) -> Result<FieldAttr, Error> {
-    match &nested[0] {
-        syn::NestedMeta::Meta(meta) => match meta {
+// Parses the comma separated items of an attribute of the form `#[name(...)]`.
+fn parse_nested(
+    raw_attr: &syn::Attribute,
+) -> Result<Punctuated<NestedMeta, syn::Token![,]>, Error> {
+    raw_attr
+        .parse_args_with(Punctuated::parse_terminated)
+        .map_err(|_| Error::NotMetaList(raw_attr.clone()))
+}
+
+fn parse_attr(nested: NestedMeta) -> Result<FieldAttr, Error> {
+    match nested {
+        NestedMeta::Meta(meta) => match &meta {
             // Single word attributes:
             // * `#[builder(default)]`
+            // * `#[builder(once_cell)]`
+            // * `#[builder(once)]`
+            // * `#[builder(keep_collection_setter)]`
+            // * `#[builder(per_index)]`
+            // * `#[builder(into)]`
+            // * `#[builder(no_into)]`
+            // * `#[builder(to_string)]`
+            // * `#[builder(secret)]`
             // * `#[builder(skip)]`
-            syn::Meta::Path(path) => {
-                let name = &path.segments[0].ident;
-
-                match name.to_string().as_str() {
-                    "default" => Ok(FieldAttr::Default(None)),
-                    "skip" => Ok(FieldAttr::Skip),
-                    _ => Err(Error::UnknownAttr(meta.clone())),
-                }
-            }
+            // * `#[builder(required)]`
+            // * `#[builder(each_builder)]`
+            Meta::Path(name) => match name.to_string().as_str() {
+                "default" => Ok(FieldAttr::Default(None)),
+                "keep_collection_setter" => Ok(FieldAttr::KeepCollectionSetter),
+                "per_index" => Ok(FieldAttr::PerIndex),
+                "into" => Ok(FieldAttr::Into),
+                "no_into" => Ok(FieldAttr::NoInto),
+                "to_string" => Ok(FieldAttr::ToString),
+                "secret" => Ok(FieldAttr::Secret),
+                "once_cell" => Ok(FieldAttr::OnceCell),
+                "once" => Ok(FieldAttr::Once),
+                "flatten" => Ok(FieldAttr::Flatten),
+                "each_builder" => Ok(FieldAttr::EachBuilder),
+                "skip" => Ok(FieldAttr::Skip),
+                "required" => Ok(FieldAttr::Required),
+                _ => Err(Error::UnknownAttr(meta)),
+            },
             // Name value attributes:
             // * `#[builder(each = "name")]`
-            // * `#[builder(default = lit)]`
-            syn::Meta::NameValue(name_value) => {
-                let name = &name_value.path.segments[0].ident;
+            // * `#[builder(name = "name")]`
+            // * `#[builder(name = [name, alias])]`
+            // * `#[builder(alias = "alias")]`
+            // * `#[builder(alias = [alias, other])]`
+            // * `#[builder(extend = "name")]`
+            // * `#[builder(default = value)]`
+            // * `#[builder(on_push = hook)]`
+            // * `#[builder(value = value)]`
+            // * `#[builder(default_from = |s| ...)]`
+            // * `#[builder(try_default = value)]`
+            // * `#[builder(try_value = value)]`
+            // * `#[builder(check = check)]`
+            // * `#[builder(check_with = check)]`
+            // * `#[builder(check_error = "Type")]`
+            // * `#[builder(deprecated = "note")]`
+            // * `#[builder(vis = "pub(crate)")]`
+            // * `#[builder(setter = wrap)]`
+            // * `#[builder(setter = set_if_some)]`
+            Meta::NameValue(name, _, value) => match name.to_string().as_str() {
+                "each" => {
+                    let each = extract_value(value)?;
 
-                match name.to_string().as_str() {
-                    "each" => {
-                        let each = extract_value(name_value)?;
+                    Ok(FieldAttr::Repeat(each))
+                }
+                "name" => {
+                    let names = extract_names(value)?;
 
-                        Ok(FieldAttr::Repeat(each))
-                    }
-                    "default" => Ok(FieldAttr::Default(Some(name_value.lit.clone()))),
-                    _ => Err(Error::UnknownAttr(meta.clone())),
+                    Ok(FieldAttr::Name(names))
                 }
-            }
-            syn::Meta::List(list) => Err(Error::NestedMetaList(list.clone())),
+                "alias" => {
+                    let aliases = extract_names(value)?;
+
+                    Ok(FieldAttr::Alias(aliases))
+                }
+                "extend" => {
+                    let extend = extract_value(value)?;
+
+                    Ok(FieldAttr::Extend(extend))
+                }
+                "deprecated" => {
+                    let note = extract_value(value)?;
+
+                    Ok(FieldAttr::Deprecated(note))
+                }
+                "vis" => {
+                    let vis = extract_value(value)?;
+
+                    syn::parse_str(&vis)
+                        .map(|vis| FieldAttr::Vis(Box::new(vis)))
+                        .map_err(|_| Error::NotVisibility(value.clone()))
+                }
+                "default" => Ok(FieldAttr::Default(Some(value.clone()))),
+                "on_push" => Ok(FieldAttr::OnPush(value.clone())),
+                "value" => Ok(FieldAttr::Value(value.clone())),
+                "default_from" => Ok(FieldAttr::DefaultFrom(value.clone())),
+                "try_default" => Ok(FieldAttr::TryDefault(value.clone())),
+                "try_value" => Ok(FieldAttr::TryValue(value.clone())),
+                "check" => Ok(FieldAttr::Check(value.clone())),
+                "check_with" => Ok(FieldAttr::CheckWith(value.clone())),
+                "check_error" => {
+                    let error = extract_value(value)?;
+
+                    syn::parse_str(&error)
+                        .map(|error| FieldAttr::CheckError(Box::new(error)))
+                        .map_err(|_| Error::NotType(value.clone()))
+                }
+                "setter" => match value {
+                    Value::Path(path) if path.is_ident("wrap") => Ok(FieldAttr::Wrap),
+                    Value::Path(path) if path.is_ident("set_if_some") => Ok(FieldAttr::SetIfSome),
+                    _ => Err(Error::UnknownAttr(meta)),
+                },
+                _ => Err(Error::UnknownAttr(meta)),
+            },
+            Meta::List(..) => Err(Error::NestedMetaList(meta)),
         },
-        syn::NestedMeta::Lit(lit) => Err(Error::UnexpectedLit(lit.clone())),
+        NestedMeta::Lit(lit) => Err(Error::UnexpectedLit(lit)),
     }
 }
 
-fn extract_value(name_value: &syn::MetaNameValue) -> Result<String, Error> {
-    if let syn::Lit::Str(lit_str) = &name_value.lit {
+fn extract_value(value: &Value) -> Result<String, Error> {
+    if let Value::Lit(syn::Lit::Str(lit_str)) = value {
         Ok(lit_str.value())
     } else {
-        Err(Error::NotStrValue(name_value.lit.clone()))
+        Err(Error::NotStrValue(value.clone()))
     }
 }
 
+// Extracts the names of `#[builder(name = ...)]`, which is either a string or a non-empty list of identifiers or strings.
+fn extract_names(value: &Value) -> Result<Vec<String>, Error> {
+    let Value::Expr(expr) = value else {
+        return Ok(vec![extract_value(value)?]);
+    };
+
+    let syn::Expr::Array(array) = expr.as_ref() else {
+        return Err(Error::NotStrValue(value.clone()));
+    };
+
+    if array.elems.is_empty() {
+        return Err(Error::NotStrValue(value.clone()));
+    }
+
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            syn::Expr::Path(syn::ExprPath { path, .. }) if path.get_ident().is_some() => {
+                Ok(path.get_ident().unwrap().to_string())
+            }
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => Ok(lit_str.value()),
+            _ => Err(Error::NotStrValue(value.clone())),
+        })
+        .collect()
+}
+
+// Attributes that can be specified at most once per field.
+// Otherwise, one of the values would silently win over the others.
+const UNIQUE_ATTRS: &[&str] = &[
+    "value",
+    "try_value",
+    "each",
+    "name",
+    "check",
+    "check_with",
+    "check_error",
+    "deprecated",
+    "vis",
+];
+
 // Parses and returns the attributes of the `field`.
 pub fn parse_attrs(field: &syn::Field) -> Result<FieldAttrs, Error> {
     let mut parsed_attrs = vec![];
+    let mut seen_attrs = vec![];
 
     for raw_attr in &field.attrs {
-        if let Ok(syn::Meta::List(syn::MetaList { nested, .. })) = raw_attr.parse_meta() {
-            parsed_attrs.push(parse_attr(&nested)?);
-        } else {
-            return Err(Error::NotMetaList(raw_attr.clone()));
+        if raw_attr.path.is_ident("cfg") {
+            parsed_attrs.push(FieldAttr::Cfg(raw_attr.clone()));
+        } else if raw_attr.path.is_ident("builder") {
+            for nested in parse_nested(raw_attr)? {
+                let name = match &nested {
+                    NestedMeta::Meta(Meta::NameValue(name, _, _)) => Some(name),
+                    NestedMeta::Meta(Meta::Path(name)) if name == "each" => Some(name),
+                    _ => None,
+                };
+
+                if let Some(name) = name {
+                    if UNIQUE_ATTRS.contains(&name.to_string().as_str()) {
+                        if seen_attrs.contains(name) {
+                            return Err(Error::DuplicateAttr(name.clone()));
+                        }
+
+                        seen_attrs.push(name.clone());
+                    }
+                }
+
+                // The bare `each` names the repeated setter after the field, so it's parsed here.
+                if let NestedMeta::Meta(Meta::Path(name)) = &nested {
+                    if name == "each" {
+                        let field_name = field.ident.as_ref().unwrap().to_string();
+                        parsed_attrs.push(FieldAttr::Repeat(singularize(&field_name)));
+
+                        continue;
+                    }
+                }
+
+                parsed_attrs.push(parse_attr(nested)?);
+            }
         }
     }
 
     Ok(FieldAttrs(parsed_attrs))
 }
 
+// Returns the name of the repeated setter of `#[builder(each)]` on a field named `name`.
+// The singular is found by naively stripping the plural suffix, e.g. `items` becomes `item`, `entries` becomes `entry`,
+// and `addresses`, `prefixes`, `batches`, and `dishes` lose their `es`.
+// When that's ambiguous, like for `status` or `data`, or it's a keyword, like for `types`,
+// the name of the field is followed by `_item` instead. A raw identifier like `r#items` is singularized without its `r#`.
+fn singularize(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+
+    let singular = if let Some(stem) = name.strip_suffix("ies").filter(|stem| !stem.is_empty()) {
+        Some(format!("{stem}y"))
+    } else if ["sses", "xes", "ches", "shes"]
+        .iter()
+        .any(|suffix| name.len() > suffix.len() && name.ends_with(suffix))
+    {
+        Some(name[..name.len() - 2].to_string())
+    } else {
+        name.strip_suffix('s')
+            .filter(|stem| {
+                !stem.is_empty()
+                    && !stem.ends_with('_')
+                    && !["s", "u", "i"].iter().any(|end| stem.ends_with(end))
+            })
+            .map(str::to_string)
+    };
+
+    // `syn` doesn't parse keywords as identifiers.
+    match singular {
+        Some(singular) if syn::parse_str::<syn::Ident>(&singular).is_ok() => singular,
+        _ => format!("{name}_item"),
+    }
+}
+
 pub struct FieldAttrs(Vec<FieldAttr>);
 
 impl FieldAttrs {
+    // Gives a `PhantomData` field its only value unless it already has a default value.
+    // It's zero-sized, so it gets no setter and doesn't need to be set before `build`.
+    pub fn phantom_data(&mut self) {
+        if self.is_default().is_none()
+            && self.try_default().is_none()
+            && self.default_from().is_none()
+        {
+            let value = syn::parse_quote! { ::core::marker::PhantomData };
+
+            self.0.push(FieldAttr::Value(Value::Path(value)));
+        }
+    }
+
     pub fn should_skip(&self) -> bool {
-        self.0.iter().any(|attr| matches!(&attr, FieldAttr::Skip))
+        self.0.iter().any(|attr| {
+            matches!(
+                &attr,
+                FieldAttr::Skip | FieldAttr::Value(_) | FieldAttr::TryValue(_)
+            )
+        })
     }
 
+    // A field with a `value` is a default field that is never set by the user.
     #[allow(clippy::option_option)]
-    pub fn is_default(&self) -> Option<Option<syn::Lit>> {
+    pub fn is_default(&self) -> Option<Option<&Value>> {
+        self.0.iter().find_map(|attr| match attr {
+            FieldAttr::Default(default) => Some(default.as_ref()),
+            FieldAttr::Value(value) => Some(Some(value)),
+            _ => None,
+        })
+    }
+
+    // A field with a `try_value` is a fallible default field that is never set by the user.
+    pub fn try_default(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| match attr {
+            FieldAttr::TryDefault(value) | FieldAttr::TryValue(value) => Some(value),
+            _ => None,
+        })
+    }
+
+    pub fn default_from(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::DefaultFrom(value) = attr {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn check(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::Check(check) = attr {
+                Some(check)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn check_with(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::CheckWith(check) = attr {
+                Some(check)
+            } else {
+                None
+            }
+        })
+    }
+
+    // Returns whether the setter of the field checks its value using `check` or `check_with`.
+    pub fn checked(&self) -> bool {
+        self.check().is_some() || self.check_with().is_some()
+    }
+
+    pub fn check_error(&self) -> Option<&syn::Type> {
         self.0.iter().find_map(|attr| {
-            if let FieldAttr::Default(default) = attr {
-                Some(default.clone())
+            if let FieldAttr::CheckError(error) = attr {
+                Some(error.as_ref())
             } else {
                 None
             }
         })
     }
 
+    pub fn once(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, FieldAttr::Once))
+    }
+
+    pub fn flatten(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::Flatten))
+    }
+
+    pub fn each_builder(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::EachBuilder))
+    }
+
+    pub fn once_cell(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::OnceCell))
+    }
+
+    pub fn wrap(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, FieldAttr::Wrap))
+    }
+
+    pub fn into(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, FieldAttr::Into))
+    }
+
+    pub fn no_into(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, FieldAttr::NoInto))
+    }
+
+    pub fn required(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::Required))
+    }
+
+    pub fn to_string(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::ToString))
+    }
+
+    pub fn secret(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, FieldAttr::Secret))
+    }
+
+    pub fn set_if_some(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::SetIfSome))
+    }
+
+    pub fn per_index(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::PerIndex))
+    }
+
+    pub fn keep_collection_setter(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, FieldAttr::KeepCollectionSetter))
+    }
+
     pub fn repeated(&self) -> Option<&String> {
         self.0.iter().find_map(|attr| {
             if let FieldAttr::Repeat(each) = attr {
@@ -104,4 +757,396 @@ impl FieldAttrs {
             }
         })
     }
+
+    pub fn on_push(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::OnPush(hook) = attr {
+                Some(hook)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn cfgs(&self) -> Vec<&syn::Attribute> {
+        self.0
+            .iter()
+            .filter_map(|attr| {
+                if let FieldAttr::Cfg(cfg) = attr {
+                    Some(cfg)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::Name(names) = attr {
+                names.first()
+            } else {
+                None
+            }
+        })
+    }
+
+    // Returns the aliases given by `name` after the name of the setter, followed by the ones given by `alias`.
+    pub fn aliases(&self) -> Vec<&String> {
+        self.0
+            .iter()
+            .flat_map(|attr| match attr {
+                FieldAttr::Name(names) => &names[1..],
+                FieldAttr::Alias(aliases) => &aliases[..],
+                _ => &[],
+            })
+            .collect()
+    }
+
+    pub fn deprecated(&self) -> Option<&String> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::Deprecated(note) = attr {
+                Some(note)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn vis(&self) -> Option<&syn::Visibility> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::Vis(vis) = attr {
+                Some(vis.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn extend(&self) -> Option<&String> {
+        self.0.iter().find_map(|attr| {
+            if let FieldAttr::Extend(extend) = attr {
+                Some(extend)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+fn parse_struct_attr(nested: NestedMeta) -> Result<StructAttr, Error> {
+    match nested {
+        NestedMeta::Meta(meta) => match meta {
+            // Single word attributes:
+            // * `#[builder(no_std)]`
+            // * `#[builder(test_only)]`
+            // * `#[builder(trace)]`
+            // * `#[builder(both_modes)]`
+            // * `#[builder(struct_debug)]`
+            // * `#[builder(into_builder)]`
+            // * `#[builder(partial)]`
+            // * `#[builder(setters_trait)]`
+            // * `#[builder(const)]`
+            // * `#[builder(from_pairs)]`
+            // * `#[builder(default)]`
+            // * `#[builder(into)]`
+            // * `#[builder(str_setters)]`
+            // * `#[builder(state_enum)]`
+            Meta::Path(name) if name == "no_std" => Ok(StructAttr::NoStd),
+            Meta::Path(name) if name == "test_only" => Ok(StructAttr::TestOnly),
+            Meta::Path(name) if name == "trace" => {
+                // The setters call `log` which the derive can't check for, so it's opted into with a feature.
+                if !cfg!(feature = "trace") {
+                    return Err(Error::TraceWithoutFeature(name));
+                }
+
+                Ok(StructAttr::Trace)
+            }
+            Meta::Path(name) if name == "both_modes" => Ok(StructAttr::BothModes),
+            Meta::Path(name) if name == "struct_debug" => Ok(StructAttr::StructDebug),
+            Meta::Path(name) if name == "into_builder" => Ok(StructAttr::IntoBuilder),
+            Meta::Path(name) if name == "partial" => Ok(StructAttr::Partial),
+            Meta::Path(name) if name == "setters_trait" => Ok(StructAttr::SettersTrait),
+            Meta::Path(name) if name == "const" => Ok(StructAttr::Const),
+            Meta::Path(name) if name == "from_pairs" => Ok(StructAttr::FromPairs),
+            Meta::Path(name) if name == "default" => Ok(StructAttr::Default),
+            Meta::Path(name) if name == "into" => Ok(StructAttr::Into),
+            Meta::Path(name) if name == "str_setters" => Ok(StructAttr::StrSetters),
+            Meta::Path(name) if name == "state_enum" => Ok(StructAttr::StateEnum),
+            // Name value attributes:
+            // * `#[builder(feature = "name")]`
+            // * `#[builder(deprecated = "note")]`
+            // * `#[builder(repair = repair)]`
+            // * `#[builder(setter_prefix = "prefix")]`
+            // * `#[builder(setter_prefix = type)]`
+            // * `#[builder(error = "Type")]`
+            // * `#[builder(rename_all = "camelCase")]`
+            // * `#[builder(storage = maybe_uninit)]`
+            // * `#[builder(mode = runtime)]`
+            Meta::NameValue(name, _, value) if name == "feature" => {
+                let feature = extract_value(&value)?;
+
+                Ok(StructAttr::Feature(feature))
+            }
+            Meta::NameValue(name, _, value) if name == "deprecated" => {
+                let note = extract_value(&value)?;
+
+                Ok(StructAttr::Deprecated(note))
+            }
+            Meta::NameValue(name, _, value) if name == "repair" => Ok(StructAttr::Repair(value)),
+            Meta::NameValue(name, _, value) if name == "setter_prefix" => match &value {
+                Value::Path(path) if path.is_ident("type") => Ok(StructAttr::SetterPrefix(None)),
+                _ => {
+                    let prefix = extract_value(&value)?;
+
+                    // The prefix starts the names of the setters, so it must be the start of an identifier.
+                    let is_ident_start = prefix.chars().all(|c| c == '_' || c.is_alphanumeric())
+                        && !prefix.starts_with(|c: char| c.is_numeric());
+                    if !is_ident_start {
+                        return Err(Error::InvalidSetterPrefix(value));
+                    }
+
+                    Ok(StructAttr::SetterPrefix(Some(prefix)))
+                }
+            },
+            Meta::NameValue(name, _, value) if name == "rename_all" => {
+                let case = match extract_value(&value)?.as_str() {
+                    "lowercase" => convert_case::Case::Flat,
+                    "UPPERCASE" => convert_case::Case::UpperFlat,
+                    "PascalCase" => convert_case::Case::Pascal,
+                    "camelCase" => convert_case::Case::Camel,
+                    "snake_case" => convert_case::Case::Snake,
+                    "SCREAMING_SNAKE_CASE" => convert_case::Case::ScreamingSnake,
+                    _ => return Err(Error::UnsupportedCase(value)),
+                };
+
+                Ok(StructAttr::RenameAll(case))
+            }
+            Meta::NameValue(name, eq, value) if name == "storage" => match &value {
+                Value::Path(path) if path.is_ident("option") => Ok(StructAttr::Storage(false)),
+                Value::Path(path) if path.is_ident("maybe_uninit") => Ok(StructAttr::Storage(true)),
+                _ => Err(Error::UnknownAttr(Meta::NameValue(name, eq, value))),
+            },
+            Meta::NameValue(name, eq, value) if name == "mode" => match &value {
+                Value::Path(path) if path.is_ident("typestate") => Ok(StructAttr::Mode(false)),
+                Value::Path(path) if path.is_ident("runtime") => Ok(StructAttr::Mode(true)),
+                _ => Err(Error::UnknownAttr(Meta::NameValue(name, eq, value))),
+            },
+            Meta::NameValue(name, _, value) if name == "error" => {
+                let error = extract_value(&value)?;
+
+                syn::parse_str(&error)
+                    .map(|error| StructAttr::Error(Box::new(error)))
+                    .map_err(|_| Error::NotType(value))
+            }
+            // List attributes:
+            // * `#[builder(derive(Clone))]`
+            Meta::List(name, _, nested) if name == "derive" => {
+                let mut traits = vec![];
+
+                for nested in nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(name)) if name == "Clone" => traits.push(name),
+                        _ => return Err(Error::UnsupportedDerive(nested)),
+                    }
+                }
+
+                Ok(StructAttr::Derive(traits))
+            }
+            _ => Err(Error::UnknownAttr(meta)),
+        },
+        NestedMeta::Lit(lit) => Err(Error::UnexpectedLit(lit)),
+    }
+}
+
+// Parses and returns the `builder` attributes of the struct.
+// Other attributes of the struct like `derive` or doc comments are ignored.
+pub fn parse_struct_attrs(ast: &syn::DeriveInput) -> Result<StructAttrs, Error> {
+    let mut parsed_attrs = vec![];
+
+    for raw_attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("builder"))
+    {
+        for nested in parse_nested(raw_attr)? {
+            parsed_attrs.push(parse_struct_attr(nested)?);
+        }
+    }
+
+    Ok(StructAttrs(parsed_attrs))
+}
+
+pub struct StructAttrs(Vec<StructAttr>);
+
+impl StructAttrs {
+    pub fn derives(&self, trait_name: &str) -> bool {
+        self.0.iter().any(|attr| {
+            if let StructAttr::Derive(traits) = attr {
+                traits.iter().any(|ident| ident == trait_name)
+            } else {
+                false
+            }
+        })
+    }
+
+    pub fn no_std(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, StructAttr::NoStd))
+    }
+
+    pub fn test_only(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::TestOnly))
+    }
+
+    pub fn trace(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, StructAttr::Trace))
+    }
+
+    pub fn both_modes(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::BothModes))
+    }
+
+    pub fn maybe_uninit(&self) -> bool {
+        self.0
+            .iter()
+            .rev()
+            .find_map(|attr| match attr {
+                StructAttr::Storage(maybe_uninit) => Some(*maybe_uninit),
+                _ => None,
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn runtime_mode(&self) -> bool {
+        self.0
+            .iter()
+            .rev()
+            .find_map(|attr| match attr {
+                StructAttr::Mode(runtime) => Some(*runtime),
+                _ => None,
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn struct_debug(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::StructDebug))
+    }
+
+    pub fn converts_into_builder(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::IntoBuilder))
+    }
+
+    pub fn setters_into(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, StructAttr::Into))
+    }
+
+    pub fn partial(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::Partial))
+    }
+
+    pub fn setters_trait(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::SettersTrait))
+    }
+
+    pub fn default(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::Default))
+    }
+
+    pub fn builds_from_pairs(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::FromPairs))
+    }
+
+    pub fn const_setters(&self) -> bool {
+        self.0.iter().any(|attr| matches!(&attr, StructAttr::Const))
+    }
+
+    pub fn str_setters(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::StrSetters))
+    }
+
+    pub fn state_enum(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(&attr, StructAttr::StateEnum))
+    }
+
+    // Returns the prefix of the setters, which is `None` if it's the name of the struct.
+    #[allow(clippy::option_option)]
+    pub fn setter_prefix(&self) -> Option<Option<&String>> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::SetterPrefix(prefix) = attr {
+                Some(prefix.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn feature(&self) -> Option<&String> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::Feature(feature) = attr {
+                Some(feature)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn deprecated(&self) -> Option<&String> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::Deprecated(note) = attr {
+                Some(note)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn rename_all(&self) -> Option<convert_case::Case> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::RenameAll(case) = attr {
+                Some(*case)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn error(&self) -> Option<&syn::Type> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::Error(error) = attr {
+                Some(error.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn repair(&self) -> Option<&Value> {
+        self.0.iter().find_map(|attr| {
+            if let StructAttr::Repair(repair) = attr {
+                Some(repair)
+            } else {
+                None
+            }
+        })
+    }
 }
@@ -1,3 +1,9 @@
+use std::fmt;
+
+use quote::ToTokens;
+
+use crate::attribute::{Meta, NestedMeta, Value};
+
 #[derive(Debug)]
 pub enum Error {
     Enum(syn::DataEnum),
@@ -5,68 +11,245 @@ pub enum Error {
     UnnamedFields(syn::Fields),
     UnitStruct(syn::Fields),
     NotMetaList(syn::Attribute),
-    NotStrValue(syn::Lit),
+    NotStrValue(Value),
     UnexpectedLit(syn::Lit),
-    NestedMetaList(syn::MetaList),
-    UnknownAttr(syn::Meta),
+    NestedMetaList(Meta),
+    UnknownAttr(Meta),
     UnsupportedType(syn::Type),
     SkipRequired(syn::Field),
+    UnsupportedDerive(NestedMeta),
+    OnceCellWithoutDefault(syn::Field),
+    OnceCellNoStd(syn::Field),
+    NotSmartPointer(syn::Type),
+    DefaultFromConflict(syn::Field),
+    PerIndexNotArray(syn::Field),
+    SetIfSomeRequired(syn::Field),
+    DuplicateAttr(syn::Ident),
+    NotCollection(syn::Type),
+    OnPushNotVec(syn::Type),
+    NotType(Value),
+    NotVisibility(Value),
+    UnsupportedCase(Value),
+    InvalidSetterPrefix(Value),
+    MaybeUninitBothModes(syn::Ident),
+    MaybeUninitRuntime(syn::Ident),
+    TryDefaultConflict(syn::Field),
+    TryDefaultNoStd(syn::Field),
+    CheckConflict(syn::Field),
+    CheckErrorWithoutCheck(syn::Field),
+    CheckWithConflict(syn::Field),
+    EachBuilderConflict(syn::Field),
+    RequiredConflict(syn::Field),
+    RequiredIntoBuilder(syn::Field),
+    ToStringInto(syn::Field),
+    OnceConflict(syn::Field),
+    OnceUntracked(syn::Field),
+    FlattenConflict(syn::Field),
+    FromPairsType(syn::Type),
+    FromPairsConflict(syn::Field),
+    MaybeUninitFromPairs(syn::Ident),
+    DefaultTryDefault(syn::Field),
+    StateEnumTooLarge(syn::Ident),
+    TraceWithoutFeature(syn::Ident),
+}
+
+impl Error {
+    // Returns the tokens that the compile error of the error points at.
+    fn spanned_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            Error::Enum(enum_t) => enum_t.enum_token.to_token_stream(),
+            Error::Union(union_t) => union_t.union_token.to_token_stream(),
+            Error::UnnamedFields(tokens) | Error::UnitStruct(tokens) => tokens.to_token_stream(),
+            Error::NotMetaList(tokens) => tokens.to_token_stream(),
+            Error::NotStrValue(tokens)
+            | Error::NotType(tokens)
+            | Error::NotVisibility(tokens)
+            | Error::UnsupportedCase(tokens)
+            | Error::InvalidSetterPrefix(tokens) => tokens.to_token_stream(),
+            Error::UnexpectedLit(tokens) => tokens.to_token_stream(),
+            Error::NestedMetaList(tokens) | Error::UnknownAttr(tokens) => tokens.to_token_stream(),
+            Error::UnsupportedType(tokens)
+            | Error::NotSmartPointer(tokens)
+            | Error::NotCollection(tokens)
+            | Error::OnPushNotVec(tokens)
+            | Error::FromPairsType(tokens) => tokens.to_token_stream(),
+            Error::SkipRequired(tokens)
+            | Error::OnceCellWithoutDefault(tokens)
+            | Error::OnceCellNoStd(tokens)
+            | Error::DefaultFromConflict(tokens)
+            | Error::PerIndexNotArray(tokens)
+            | Error::SetIfSomeRequired(tokens)
+            | Error::TryDefaultConflict(tokens)
+            | Error::TryDefaultNoStd(tokens)
+            | Error::CheckConflict(tokens)
+            | Error::CheckErrorWithoutCheck(tokens)
+            | Error::CheckWithConflict(tokens)
+            | Error::EachBuilderConflict(tokens)
+            | Error::RequiredConflict(tokens)
+            | Error::RequiredIntoBuilder(tokens)
+            | Error::ToStringInto(tokens)
+            | Error::OnceConflict(tokens)
+            | Error::OnceUntracked(tokens)
+            | Error::FlattenConflict(tokens)
+            | Error::FromPairsConflict(tokens)
+            | Error::DefaultTryDefault(tokens) => tokens.to_token_stream(),
+            Error::UnsupportedDerive(tokens) => tokens.to_token_stream(),
+            Error::DuplicateAttr(tokens)
+            | Error::MaybeUninitBothModes(tokens)
+            | Error::MaybeUninitRuntime(tokens)
+            | Error::MaybeUninitFromPairs(tokens)
+            | Error::StateEnumTooLarge(tokens)
+            | Error::TraceWithoutFeature(tokens) => tokens.to_token_stream(),
+        }
+    }
 }
 
+// The messages are the ones of the compile errors.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Enum(_) => f.write_str("Builder does not support enums"),
+            Error::Union(_) => f.write_str("Builder does not support unions"),
+            Error::UnnamedFields(_) => f.write_str("Builder does not support unnamed fields"),
+            Error::UnitStruct(_) => f.write_str("Builder does not support unit structs"),
+            Error::NotMetaList(_) => f.write_str("Provided attribute is malformed"),
+            Error::NotStrValue(_) => f.write_str("Value must be a string"),
+            Error::UnexpectedLit(_) => f.write_str("Not expected a literal"),
+            Error::NestedMetaList(_) => f.write_str("Nested attributes are not supported"),
+            Error::UnknownAttr(_) => f.write_str("Unknown attribute"),
+            Error::UnsupportedType(_) => f.write_str("Only segmented paths are supported"),
+            Error::SkipRequired(_) => f.write_str("Cannot skip a required field without a `value`"),
+            Error::UnsupportedDerive(_) => f.write_str("Only `Clone` can be derived for the builder"),
+            Error::OnceCellWithoutDefault(_) => f.write_str("`once_cell` requires a default value"),
+            Error::OnceCellNoStd(_) => f.write_str("`once_cell` is not supported in `no_std`"),
+            Error::NotSmartPointer(_) => f.write_str("`setter = wrap` is only supported for `Box`, `Rc`, and `Arc`"),
+            Error::DefaultFromConflict(_) => f.write_str("`default_from` cannot be combined with `default`, `once_cell`, `each`, or `extend`"),
+            Error::PerIndexNotArray(_) => f.write_str("`per_index` requires a required array field with a literal length"),
+            Error::SetIfSomeRequired(_) => f.write_str("`setter = set_if_some` is not supported for required fields"),
+            Error::DuplicateAttr(name) => write!(f, "duplicate `{}` attribute", name),
+            Error::NotCollection(_) => f.write_str("`each` and `extend` are only supported for `Vec`, `VecDeque`, `LinkedList`, `HashSet`, `BTreeSet`, `BinaryHeap`, `HashMap`, and `BTreeMap` fields"),
+            Error::OnPushNotVec(_) => f.write_str("`on_push` is only supported for `Vec` fields"),
+            Error::NotType(_) => f.write_str("Value must be a type"),
+            Error::NotVisibility(_) => f.write_str("Value must be a visibility, like `pub(crate)`"),
+            Error::UnsupportedCase(_) => f.write_str("Expected one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", or \"SCREAMING_SNAKE_CASE\""),
+            Error::InvalidSetterPrefix(_) => f.write_str("`setter_prefix` must only contain letters, digits, and `_`, and cannot start with a digit"),
+            Error::MaybeUninitBothModes(_) => f.write_str("`storage = maybe_uninit` cannot be combined with `both_modes`"),
+            Error::MaybeUninitRuntime(_) => f.write_str("`storage = maybe_uninit` cannot be combined with `mode = runtime`"),
+            Error::TryDefaultConflict(_) => f.write_str("`try_default` and `try_value` cannot be combined with `default`, `value`, `default_from`, or `per_index`"),
+            Error::TryDefaultNoStd(_) => f.write_str("`try_default` and `try_value` require `#[builder(error = \"Type\")]` in `no_std` structs"),
+            Error::CheckConflict(_) => f.write_str("`check` cannot be combined with `each`, `extend`, `per_index`, `skip`, `value`, or `try_value`"),
+            Error::CheckErrorWithoutCheck(_) => f.write_str("`check_error` requires a `check` or a `check_with`"),
+            Error::CheckWithConflict(_) => f.write_str("`check_with` cannot be combined with `check`, `each`, `extend`, `per_index`, `skip`, `value`, or `try_value`"),
+            Error::EachBuilderConflict(_) => f.write_str("`each_builder` requires an `each` whose items are a struct that derives `Builder`"),
+            Error::RequiredConflict(_) => f.write_str("`required` is only supported for `Option` fields without a default value or `skip`"),
+            Error::RequiredIntoBuilder(_) => f.write_str("`required` fields cannot be converted back into the builder by `into_builder` since they may be `None`"),
+            Error::ToStringInto(_) => f.write_str("`to_string` cannot be combined with `into`"),
+            Error::OnceConflict(_) => f.write_str("`once` is only supported for optional fields without `setter = set_if_some` or `skip`"),
+            Error::OnceUntracked(_) => f.write_str("`once` cannot be combined with `both_modes` or `mode = runtime` since their builders don't track which fields are set"),
+            Error::FlattenConflict(_) => f.write_str("`flatten` is only supported for required fields whose type is a path to a struct deriving `Builder`, without `each`, `extend`, `setter = wrap`, or `to_string`"),
+            Error::FromPairsType(_) => f.write_str("`from_pairs` requires the values of all the fields to have the same type"),
+            Error::FromPairsConflict(_) => f.write_str("`from_pairs` cannot be combined with `check`, `check_with`, or `try_default`"),
+            Error::MaybeUninitFromPairs(_) => f.write_str("`storage = maybe_uninit` cannot be combined with `from_pairs`"),
+            Error::DefaultTryDefault(_) => f.write_str("`default` on the struct cannot be combined with `try_default`"),
+            Error::StateEnumTooLarge(_) => f.write_str("`state_enum` supports at most 6 required fields and fields with `once`, since it has a variant for each of their combinations"),
+            Error::TraceWithoutFeature(_) => f.write_str("`trace` requires the `trace` feature of tidy-builder, and the crate using the builder must depend on `log`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 impl From<Error> for proc_macro::TokenStream {
     fn from(error: Error) -> proc_macro::TokenStream {
-        match error {
-            Error::Enum(enum_t) => {
-                syn::Error::new_spanned(enum_t.enum_token, "Builder does not support enums")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::Union(union_t) => {
-                syn::Error::new_spanned(union_t.union_token, "Builder does not support unions")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::UnnamedFields(fields) => {
-                syn::Error::new_spanned(fields, "Builder does not support unnamed fields")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::UnitStruct(fields) => {
-                syn::Error::new_spanned(fields, "Builder does not support unit structs")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::NotMetaList(attr) => {
-                syn::Error::new_spanned(attr, "Provided attribute is malformed")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::NotStrValue(lit) => syn::Error::new_spanned(lit, "Value must be a string")
-                .into_compile_error()
-                .into(),
-            Error::UnexpectedLit(lit) => syn::Error::new_spanned(lit, "Not expected a literal")
-                .into_compile_error()
-                .into(),
-            Error::NestedMetaList(meta_list) => {
-                syn::Error::new_spanned(meta_list, "Nested attributes are not supported")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::UnknownAttr(name_value) => {
-                syn::Error::new_spanned(name_value, "Unknown attribute")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::UnsupportedType(ty) => {
-                syn::Error::new_spanned(ty, "Only segmented paths are supported")
-                    .into_compile_error()
-                    .into()
-            }
-            Error::SkipRequired(field) => {
-                syn::Error::new_spanned(field, "Cannot skip a required field")
-                    .into_compile_error()
-                    .into()
+        syn::Error::new_spanned(error.spanned_tokens(), error.to_string())
+            .into_compile_error()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    use super::Error;
+
+    // Returns the message of the compile error that `stderr` starts with.
+    fn message(stderr: &str) -> &str {
+        stderr.lines().next().unwrap().trim_start_matches("error: ")
+    }
+
+    fn field() -> syn::Field {
+        let item: syn::ItemStruct = parse_quote! {
+            struct MyStruct {
+                names: Vec<String>,
             }
-        }
+        };
+
+        item.fields.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn messages_of_field_errors() {
+        assert_eq!(
+            Error::OnceCellWithoutDefault(field()).to_string(),
+            message(include_str!(
+                "../tests/ui/error/once_cell_without_default.stderr"
+            ))
+        );
+        assert_eq!(
+            Error::OnceCellNoStd(field()).to_string(),
+            message(include_str!("../tests/ui/error/once_cell_in_no_std.stderr"))
+        );
+        assert_eq!(
+            Error::CheckConflict(field()).to_string(),
+            message(include_str!("../tests/ui/error/check_conflict.stderr"))
+        );
+        assert_eq!(
+            Error::FlattenConflict(field()).to_string(),
+            message(include_str!("../tests/ui/error/flatten_conflict.stderr"))
+        );
+    }
+
+    #[test]
+    fn messages_of_type_errors() {
+        assert_eq!(
+            Error::NotSmartPointer(parse_quote! { Vec<String> }).to_string(),
+            message(include_str!(
+                "../tests/ui/error/wrap_non_smart_pointer.stderr"
+            ))
+        );
+        assert_eq!(
+            Error::NotCollection(parse_quote! { String }).to_string(),
+            message(include_str!("../tests/ui/error/each_not_collection.stderr"))
+        );
+    }
+
+    #[test]
+    fn messages_naming_the_attribute() {
+        assert_eq!(
+            Error::DuplicateAttr(format_ident!("value")).to_string(),
+            message(include_str!("../tests/ui/error/duplicate_attr.stderr"))
+        );
+        assert_eq!(
+            Error::DuplicateAttr(format_ident!("each")).to_string(),
+            message(include_str!("../tests/ui/error/each_duplicate_bare.stderr"))
+        );
+    }
+
+    #[test]
+    fn messages_of_struct_errors() {
+        assert_eq!(
+            Error::MaybeUninitRuntime(format_ident!("MyStruct")).to_string(),
+            message(include_str!(
+                "../tests/ui/error/runtime_maybe_uninit.stderr"
+            ))
+        );
+        assert_eq!(
+            Error::StateEnumTooLarge(format_ident!("MyStruct")).to_string(),
+            message(include_str!(
+                "../tests/ui/error/state_enum_too_large.stderr"
+            ))
+        );
     }
 }
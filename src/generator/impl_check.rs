@@ -0,0 +1,268 @@
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+
+use super::Generator;
+use crate::generics::trivial_bound;
+use crate::wrap::is_option;
+
+impl<'a> Generator<'a> {
+    // Returns the name of the error type of the setters of fields with `#[builder(check = check)]`.
+    fn check_error_ident(&self) -> syn::Ident {
+        format_ident!("{}Error", self.b_ident)
+    }
+
+    // Returns the name of the variant of the error type carrying the error of the `check` of `field`.
+    fn check_variant(&self, field: &syn::Field) -> syn::Ident {
+        format_ident!(
+            "{}",
+            field
+                .ident
+                .as_ref()
+                .unwrap()
+                .to_string()
+                .to_case(Case::Pascal)
+        )
+    }
+
+    // Returns the error type of the `check` of `field`.
+    // Defaults to `&'static str` which needs neither allocation nor `std`.
+    fn check_error_ty(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        match self.f_attrs[field].check_error() {
+            Some(error) => quote! { #error },
+            None => quote! { &'static str },
+        }
+    }
+
+    // Returns the statement validating `value` of type `ty` using the `check` or the `check_with` of `field`, if any,
+    // and the expression of the value to store.
+    //
+    // The statement returns the error of the check from the setter, wrapped in the variant of the field.
+    // The check is coerced to a `dyn FnMut` so the types of the arguments of a closure are inferred.
+    // A `check_with` also gets a reference to the builder, before the value is stored in it.
+    pub fn check_value(
+        &self,
+        field: &syn::Field,
+        ty: &syn::Type,
+        value: proc_macro2::TokenStream,
+    ) -> (Option<proc_macro2::TokenStream>, proc_macro2::TokenStream) {
+        let attrs = &self.f_attrs[field];
+        let Some(check) = attrs.check().or_else(|| attrs.check_with()) else {
+            return (None, value);
+        };
+
+        let field_ident = &field.ident;
+        let error_ident = self.check_error_ident();
+        let variant = self.check_variant(field);
+        let error_ty = self.check_error_ty(field);
+
+        // The setters take the builder either by value or by mutable reference, and `Borrow` turns both into a `&Self`.
+        let (builder_ty, builder_arg) = if attrs.check_with().is_some() {
+            (
+                Some(quote! { &Self, }),
+                Some(quote! { ::core::borrow::Borrow::borrow(&self), }),
+            )
+        } else {
+            (None, None)
+        };
+
+        (
+            Some(quote! {
+                let #field_ident = #value;
+                {
+                    let check: &mut dyn ::core::ops::FnMut(#builder_ty &#ty) -> ::core::result::Result<(), #error_ty> = &mut (#check);
+
+                    if let ::core::result::Result::Err(error) = check(#builder_arg &#field_ident) {
+                        return ::core::result::Result::Err(#error_ident::#variant(error));
+                    }
+                }
+            }),
+            quote! { #field_ident },
+        )
+    }
+
+    // Returns the `validate` method of the builder if any field has a `check` or a `check_with`.
+    //
+    // It runs the check of every field whose value is set against that value, and returns the errors of all
+    // the checks that fail rather than only the first one. Unlike the values passed to the setters,
+    // default values are never checked otherwise.
+    // It's omitted if a setter is named `validate`.
+    pub fn validate_fn(&self) -> Option<proc_macro2::TokenStream> {
+        let fields = self
+            .req_fields
+            .iter()
+            .chain(&self.opt_fields)
+            .chain(&self.def_fields);
+
+        let error_ident = self.check_error_ident();
+        let mut validations = vec![];
+        for &field in fields {
+            let attrs = &self.f_attrs[field];
+            let Some(check) = attrs.check().or_else(|| attrs.check_with()) else {
+                continue;
+            };
+
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[field].cfgs();
+            let variant = self.check_variant(field);
+            let error_ty = self.check_error_ty(field);
+
+            // Required fields, optional fields, and default fields with `default_from` may not be set yet.
+            let (ty, value) = if self.req_fields.contains(&field) {
+                (&field.ty, self.req_ref(field))
+            } else if let Some(inner_ty) =
+                is_option(&field.ty).filter(|_| self.opt_fields.contains(&field))
+            {
+                (inner_ty, quote! { self.#field_ident.as_ref() })
+            } else if self.f_attrs[field].default_from().is_some() {
+                (&field.ty, quote! { self.#field_ident.as_ref() })
+            } else {
+                (&field.ty, quote! { Some(&self.#field_ident) })
+            };
+
+            let (builder_ty, builder_arg) = if attrs.check_with().is_some() {
+                (Some(quote! { &Self, }), Some(quote! { self, }))
+            } else {
+                (None, None)
+            };
+
+            validations.push(quote! {
+                #(#cfgs)*
+                if let Some(value) = #value {
+                    let check: &mut dyn ::core::ops::FnMut(#builder_ty &#ty) -> ::core::result::Result<(), #error_ty> = &mut (#check);
+
+                    if let ::core::result::Result::Err(error) = check(#builder_arg value) {
+                        errors.push(#error_ident::#variant(error));
+                    }
+                }
+            });
+        }
+
+        if validations.is_empty() || self.has_setter_named("validate") {
+            return None;
+        }
+
+        let alloc = self.alloc();
+
+        Some(quote! {
+            pub fn validate(&self) -> ::core::result::Result<(), #alloc::vec::Vec<#error_ident>> {
+                let mut errors = #alloc::vec::Vec::new();
+
+                #(#validations)*
+
+                if errors.is_empty() {
+                    ::core::result::Result::Ok(())
+                } else {
+                    ::core::result::Result::Err(errors)
+                }
+            }
+        })
+    }
+
+    // Returns the return type and the returned value of a setter of `field` returning `value` of type `ty`.
+    // With a `check` or a `check_with`, they're wrapped in a `Result`.
+    pub fn checked_ret(
+        &self,
+        field: &syn::Field,
+        ty: proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        if !self.f_attrs[field].checked() {
+            return (ty, value);
+        }
+
+        let error_ident = self.check_error_ident();
+
+        (
+            quote! { ::core::result::Result<#ty, #error_ident> },
+            quote! { ::core::result::Result::Ok(#value) },
+        )
+    }
+
+    // Generate the error type of the setters if any field has a `check` or a `check_with`.
+    //
+    // It has a variant per checked field carrying the error of its check, named after the field in PascalCase.
+    // `Debug` and `Display` are implemented if the errors implement them, and so is `std::error::Error` unless `no_std`.
+    pub fn check_error_type(&self) -> Option<proc_macro2::TokenStream> {
+        let checked_fields: Vec<_> = self
+            .s_fields
+            .iter()
+            .filter(|field| self.f_attrs[**field].checked())
+            .collect();
+
+        if checked_fields.is_empty() {
+            return None;
+        }
+
+        let mut variants = vec![];
+        let mut debug_arms = vec![];
+        let mut display_arms = vec![];
+        let mut debug_bounds = vec![];
+        let mut display_bounds = vec![];
+
+        for &&field in &checked_fields {
+            let cfgs = self.f_attrs[field].cfgs();
+            let variant = self.check_variant(field);
+            let variant_name = variant.to_string();
+            let error_ty = self.check_error_ty(field);
+            let message = format!("invalid `{}`: {{}}", field.ident.as_ref().unwrap());
+
+            variants.push(quote! { #(#cfgs)* #variant(#error_ty) });
+            debug_arms.push(quote! {
+                #(#cfgs)*
+                Self::#variant(error) => f.debug_tuple(#variant_name).field(error).finish()
+            });
+            display_arms.push(quote! {
+                #(#cfgs)*
+                Self::#variant(error) => ::core::write!(f, #message, error)
+            });
+            debug_bounds.push(trivial_bound(&error_ty, quote! { ::core::fmt::Debug }));
+            display_bounds.push(trivial_bound(&error_ty, quote! { ::core::fmt::Display }));
+        }
+
+        let error_ident = self.check_error_ident();
+        let s_vis = self.s_vis;
+        let items_cfg = self.items_cfg();
+
+        // `core::error::Error` is too recent to rely on.
+        let error_trait = (!self.s_attrs.no_std()).then(|| {
+            quote! {
+                #items_cfg
+                impl ::std::error::Error for #error_ident
+                    where #(#debug_bounds,)* #(#display_bounds,)*
+                {
+                }
+            }
+        });
+
+        Some(quote! {
+            #items_cfg
+            #s_vis enum #error_ident {
+                #(#variants),*
+            }
+
+            #items_cfg
+            impl ::core::fmt::Debug for #error_ident
+                where #(#debug_bounds),*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(#debug_arms,)*
+                    }
+                }
+            }
+
+            #items_cfg
+            impl ::core::fmt::Display for #error_ident
+                where #(#display_bounds),*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(#display_arms,)*
+                    }
+                }
+            }
+
+            #error_trait
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use quote::quote;
+
+use super::Generator;
+use crate::generics::trivial_bound;
+
+impl<'a> Generator<'a> {
+    // Generate Clone trait impl for the builder if requested by `#[builder(derive(Clone))]`.
+    //
+    // A `#[derive(Clone)]` on the builder won't work since the derive can't see
+    // through the const generic parameters, so we implement it by cloning each field.
+    pub fn clone_trait(&self) -> Vec<proc_macro2::TokenStream> {
+        if !self.s_attrs.derives("Clone") {
+            return vec![];
+        }
+
+        let fields = self
+            .req_fields
+            .iter()
+            .chain(&self.opt_fields)
+            .chain(&self.def_fields);
+
+        let mut clones = vec![];
+        let mut clone_bounds = vec![];
+        for field in fields {
+            let field_ident = &field.ident;
+            let field_ty = &field.ty;
+
+            let cfgs = self.f_attrs[field].cfgs();
+
+            clones.push(
+                quote! { #(#cfgs)* #field_ident: ::core::clone::Clone::clone(&self.#field_ident) },
+            );
+            clone_bounds.push(trivial_bound(field_ty, quote! { ::core::clone::Clone }));
+        }
+
+        // Define these to be able to interpolate in quote.
+        let b_ident = &self.b_ident;
+        let b_const_p = &self.b_const_p;
+        let b_const_pn = &self.b_const_pn;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let predicates: Vec<_> = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .collect();
+
+        let items_cfg = self.items_cfg();
+
+        vec![quote! {
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)*> ::core::clone::Clone for
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
+                where
+                    #(#predicates,)*
+                    #(#clone_bounds,)*
+            {
+                fn clone(&self) -> Self {
+                    #b_ident {
+                        #(#clones),*
+                    }
+                }
+            }
+        }]
+    }
+}
@@ -13,7 +13,10 @@ impl<'a> Generator<'a> {
         for (field_idx, field) in self.req_fields.iter().enumerate() {
             let field_name = field.ident.as_ref().unwrap().to_string();
             let field_camel = field_name.to_case(Case::UpperCamel);
-            let trait_ident = format_ident!("Has{}", field_camel);
+            // The name of the struct keeps the guards of structs in the same module apart.
+            let trait_ident = format_ident!("{}Has{}", self.s_ident, field_camel);
+            let cfgs = self.f_attrs[*field].cfgs();
+            let items_cfg = self.items_cfg();
 
             let before_ct_pn = &self.b_const_pn[0..field_idx];
             let after_ct_pn = &self.b_const_pn[field_idx + 1..];
@@ -46,17 +49,146 @@ impl<'a> Generator<'a> {
             let st_type_p = &self.st_type_p;
 
             guard_traits.push(quote! {
+                #items_cfg
                 #error_message
                 trait #trait_ident {}
+                #items_cfg
+                #(#cfgs)*
                 impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#before_ct_p,)* #(#after_ct_p,)* #(#st_type_p,)* >
                     #trait_ident for
                     #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_ct_pn,)* true, #(#after_ct_pn,)* #(#st_type_pn,)* >
                     #where_clause { }
             });
 
+            // If the field is compiled out, there is no setter to provide its value.
+            // So the guard must hold in every state to not block the `build` function.
+            if !cfgs.is_empty() {
+                let predicates: Vec<_> = cfgs
+                    .iter()
+                    .map(|cfg| cfg.parse_args::<proc_macro2::TokenStream>())
+                    .collect::<Result<_, _>>()
+                    .unwrap_or_default();
+                let b_const_p = &self.b_const_p;
+                let b_const_pn = &self.b_const_pn;
+
+                guard_traits.push(quote! {
+                    #items_cfg
+                    #[cfg(not(all(#(#predicates),*)))]
+                    impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)* >
+                        #trait_ident for
+                        #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >
+                        #where_clause { }
+                });
+            }
+
             guard_trait_idents.push(trait_ident);
         }
 
         (guard_traits, guard_trait_idents)
     }
+
+    // Returns the `Complete` trait which is only implemented by the builder when all required fields are set.
+    // It lets generic code accept a builder that is ready to be built, without naming its const generic parameters.
+    // The trait has the same visibility as the struct since it exposes the struct as its `Output`.
+    //
+    // The trait is sealed using a supertrait that lives in a private module, so it can't be implemented outside the crate.
+    //
+    // `build` is bounded by the guards rather than by this trait, even though both hold in the same states.
+    // With the bound on this trait, the compiler reports the trait as unsatisfied and only mentions the guard in a note,
+    // which would hide the `missing field` message that `better_error` puts on the guards.
+    pub fn complete_trait(&self, guard_trait_idents: &[syn::Ident]) -> proc_macro2::TokenStream {
+        let b_ident = &self.b_ident;
+        let s_ident = &self.s_ident;
+        let s_vis = self.s_vis;
+        let complete_ident = format_ident!("{}Complete", b_ident);
+        let sealed_ident = format_ident!("__{}Sealed", b_ident);
+        let items_cfg = self.items_cfg();
+
+        // Define these to be able to interpolate in quote.
+        let ty_generics = &self.ty_generics;
+        let where_clause = &self.where_clause;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let b_const_p = &self.b_const_p;
+        let b_const_pn = &self.b_const_pn;
+        let predicates = self.where_clause.iter().flat_map(|w| w.predicates.iter());
+
+        quote! {
+            #items_cfg
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            mod #sealed_ident {
+                pub trait Sealed {}
+            }
+
+            #items_cfg
+            #s_vis trait #complete_ident: #sealed_ident::Sealed {
+                type Output;
+
+                #[must_use]
+                fn build(self) -> Self::Output;
+            }
+
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)* >
+                #sealed_ident::Sealed for
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >
+                #where_clause { }
+
+            // The guards make sure this is only implemented when all required fields are set.
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)* >
+                #complete_ident for
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >
+                where
+                    #(#predicates,)*
+                    Self: #(#guard_trait_idents)+*
+            {
+                type Output = #s_ident #ty_generics;
+
+                #[inline]
+                fn build(self) -> Self::Output {
+                    #b_ident::build(self)
+                }
+            }
+        }
+    }
+
+    // Returns the `From` impl which converts the builder into the struct when all required fields are set.
+    pub fn conversion_trait(&self, guard_trait_idents: &[syn::Ident]) -> proc_macro2::TokenStream {
+        let b_ident = &self.b_ident;
+        let s_ident = &self.s_ident;
+        let items_cfg = self.items_cfg();
+
+        // Define these to be able to interpolate in quote.
+        let ty_generics = &self.ty_generics;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let b_const_p = &self.b_const_p;
+        let b_const_pn = &self.b_const_pn;
+        let predicates = self.where_clause.iter().flat_map(|w| w.predicates.iter());
+
+        quote! {
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)* >
+                ::core::convert::From<#b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >>
+                for #s_ident #ty_generics
+                where
+                    #(#predicates,)*
+                    #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >: #(#guard_trait_idents)+*
+            {
+                fn from(builder: #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >) -> Self {
+                    builder.build()
+                }
+            }
+        }
+    }
 }
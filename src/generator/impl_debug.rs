@@ -0,0 +1,66 @@
+use quote::quote;
+
+use super::Generator;
+use crate::generics::trivial_bound;
+
+impl<'a> Generator<'a> {
+    // Generate Debug trait impl for the struct if requested by `#[builder(struct_debug)]`.
+    //
+    // Fields with `#[builder(secret)]` are printed as `<redacted>`, so they don't need to implement `Debug`.
+    pub fn struct_debug_trait(&self) -> Vec<proc_macro2::TokenStream> {
+        if !self.s_attrs.struct_debug() {
+            return vec![];
+        }
+
+        let mut debug_fields = vec![];
+        let mut debug_bounds = vec![];
+        for field in &self.s_fields {
+            let field_ident = &field.ident;
+            let field_ty = &field.ty;
+            let field_name = field_ident.as_ref().unwrap().to_string();
+
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            if self.f_attrs[*field].secret() {
+                debug_fields.push(quote! {
+                    #(#cfgs)*
+                    debug.field(#field_name, &::core::format_args!("<redacted>"));
+                });
+            } else {
+                debug_fields.push(quote! {
+                    #(#cfgs)*
+                    debug.field(#field_name, &self.#field_ident);
+                });
+                debug_bounds.push(trivial_bound(field_ty, quote! { ::core::fmt::Debug }));
+            }
+        }
+
+        // Define these to be able to interpolate in quote.
+        let s_ident = &self.s_ident;
+        let s_name = s_ident.to_string();
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let predicates: Vec<_> = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .collect();
+
+        let items_cfg = self.items_cfg();
+
+        vec![quote! {
+            #items_cfg
+            impl #impl_generics ::core::fmt::Debug for #s_ident #ty_generics
+                where
+                    #(#predicates,)*
+                    #(#debug_bounds,)*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut debug = f.debug_struct(#s_name);
+                    #(#debug_fields)*
+                    debug.finish()
+                }
+            }
+        }]
+    }
+}
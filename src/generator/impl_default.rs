@@ -1,24 +1,124 @@
-use quote::quote;
+use quote::{quote, ToTokens};
 
 use super::Generator;
+use crate::generics::{erase_lifetimes, trivial_bound};
 
 impl<'a> Generator<'a> {
-    /// Generate Default trait impl if there are no required fields
+    // Returns the bounds requiring the type of every required field to implement `Default`.
+    pub fn req_default_bounds(&self) -> Vec<proc_macro2::TokenStream> {
+        let mut req_default_bounds: Vec<proc_macro2::TokenStream> = vec![];
+        for field in &self.req_fields {
+            let field_ty = erase_lifetimes(field.ty.to_token_stream());
+            let bound = trivial_bound(field_ty, quote! { ::core::default::Default });
+
+            if !req_default_bounds
+                .iter()
+                .any(|b| b.to_string() == bound.to_string())
+            {
+                req_default_bounds.push(bound);
+            }
+        }
+
+        req_default_bounds
+    }
+
+    /// Generate Default trait impl if there are no required fields and no fallible default values.
+    /// With `#[builder(default)]` on the struct, the required fields get their `Default` value instead.
     pub fn default_trait(&self) -> Vec<proc_macro2::TokenStream> {
-        if self.req_fields.len() > 0 {
+        let struct_default = self.s_attrs.default();
+        if (self.req_fields.len() > 0 && !struct_default) || self.builder_error().is_some() {
             return vec![];
         }
 
         let impl_generics = &self.impl_generics;
         let s_ident = &self.s_ident;
         let ty_generics = &self.ty_generics;
+        let items_cfg = self.items_cfg();
+        let predicates: Vec<_> = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates)
+            .collect();
+        let req_default_bounds = if struct_default {
+            self.req_default_bounds()
+        } else {
+            vec![]
+        };
+
+        // The runtime-checked builder doesn't have `build_with_defaults`, so the required fields are set to their
+        // default value before `try_build`, which can't fail then.
+        let build = if self.s_attrs.runtime_mode() {
+            let req_sets = self.req_fields.iter().map(|field| {
+                let field_ident = &field.ident;
+                let cfgs = self.f_attrs[*field].cfgs();
+
+                quote! {
+                    #(#cfgs)*
+                    {
+                        builder.#field_ident = Some(::core::default::Default::default());
+                    }
+                }
+            });
+
+            quote! {
+                #[allow(unused_mut)]
+                let mut builder = Self::builder();
+                #(#req_sets)*
+
+                match builder.try_build() {
+                    ::core::result::Result::Ok(built) => built,
+                    ::core::result::Result::Err(_) => ::core::unreachable!(),
+                }
+            }
+        } else if struct_default {
+            quote! { Self::builder().build_with_defaults() }
+        } else {
+            quote! { Self::builder().build() }
+        };
 
         vec![quote! {
-            impl #impl_generics Default for #s_ident #ty_generics {
+            #items_cfg
+            impl #impl_generics ::core::default::Default for #s_ident #ty_generics
+                where #(#predicates,)* #(#req_default_bounds,)*
+            {
+                // `builder()` may be deprecated using `#[builder(deprecated = "note")]`.
+                #[allow(deprecated)]
                 fn default() -> Self {
-                    Self::builder().build()
+                    #build
                 }
             }
         }]
     }
+
+    /// Generate Default trait impl for the builder in its initial state
+    /// unless creating the builder is fallible
+    pub fn builder_default_trait(&self) -> Option<proc_macro2::TokenStream> {
+        if self.builder_error().is_some() {
+            return None;
+        }
+
+        let impl_generics = &self.impl_generics;
+        let where_clause = &self.where_clause;
+        let s_ident = &self.s_ident;
+        let b_ident = &self.b_ident;
+        let ty_generics = &self.ty_generics;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let all_false = &self.all_false;
+        let items_cfg = self.items_cfg();
+
+        Some(quote! {
+            #items_cfg
+            impl #impl_generics ::core::default::Default
+                for #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_false,)* #(#st_type_pn,)*>
+                #where_clause
+            {
+                #[allow(deprecated)]
+                fn default() -> Self {
+                    <#s_ident #ty_generics>::builder()
+                }
+            }
+        })
+    }
 }
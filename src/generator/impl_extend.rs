@@ -0,0 +1,70 @@
+use quote::quote;
+
+use super::Generator;
+use crate::err::Error;
+use crate::wrap::{collection_item, is_option, type_ident};
+
+impl<'a> Generator<'a> {
+    // Generate the implementation of `Extend` for the builder `builder_ty` if exactly one field has a repeated setter.
+    //
+    // It pushes the items to the collection of that field like its repeated setter, one at a time so `on_push` sees each of them.
+    // `Extend` takes the builder by mutable reference, so a builder tracking the required fields in its type
+    // can't implement it for a required field, since setting the field would change the state.
+    pub fn extend_trait(
+        &self,
+        impl_generics: proc_macro2::TokenStream,
+        builder_ty: proc_macro2::TokenStream,
+        tracks_required: bool,
+    ) -> Result<Option<proc_macro2::TokenStream>, Error> {
+        let mut repeated_fields = self
+            .req_fields
+            .iter()
+            .chain(&self.opt_fields)
+            .chain(&self.def_fields)
+            .filter(|field| self.f_attrs[**field].repeated().is_some());
+
+        let (Some(&field), None) = (repeated_fields.next(), repeated_fields.next()) else {
+            return Ok(None);
+        };
+
+        let is_req = self.req_fields.contains(&field);
+        if is_req && tracks_required {
+            return Ok(None);
+        }
+
+        let field_ident = &field.ident;
+        let value_ty = is_option(&field.ty).unwrap_or(&field.ty);
+        let item_ty = collection_item(value_ty)?;
+        let cfgs = self.f_attrs[field].cfgs();
+        let trace = self.trace(field);
+
+        // Required and optional fields are stored in an `Option`, which is only filled once an item is pushed.
+        let collection = if is_req || self.opt_fields.contains(&field) {
+            let container_ident = type_ident(value_ty)?;
+
+            quote! { self.#field_ident.get_or_insert_with(#container_ident::new) }
+        } else {
+            quote! { &mut self.#field_ident }
+        };
+        let on_push = self.on_push(field, quote! { collection.last() });
+
+        // Define these to be able to interpolate in quote.
+        let where_clause = &self.where_clause;
+        let items_cfg = self.items_cfg();
+
+        Ok(Some(quote! {
+            #items_cfg
+            #(#cfgs)*
+            impl #impl_generics ::core::iter::Extend<#item_ty> for #builder_ty #where_clause {
+                fn extend<__I: ::core::iter::IntoIterator<Item = #item_ty>>(&mut self, iter: __I) {
+                    for item in iter {
+                        #trace
+                        let collection = #collection;
+                        collection.extend(::core::option::Option::Some(item));
+                        #on_push
+                    }
+                }
+            }
+        }))
+    }
+}
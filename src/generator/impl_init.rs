@@ -2,31 +2,277 @@ use quote::quote;
 use syn::spanned::Spanned;
 
 use super::Generator;
+use crate::attribute::Value;
 
 impl<'a> Generator<'a> {
+    // Returns the code to move the required fields into the built struct.
+    // `value` returns the expression that takes the value of a required field of the builder.
+    //
+    // The elements of arrays with `#[builder(per_index)]` are put back together into the array,
+    // and the values of `Option` fields with `#[builder(required)]` are wrapped in `Some`.
+    pub fn req_values(
+        &self,
+        value: impl Fn(&syn::Field) -> proc_macro2::TokenStream,
+    ) -> Vec<proc_macro2::TokenStream> {
+        let is_slot = |field: &syn::Field| {
+            self.array_slots
+                .iter()
+                .any(|(_, slots)| slots.contains(&field))
+        };
+
+        let fields = self.req_fields.iter().filter(|field| !is_slot(field));
+        let arrays = self.array_slots.iter().map(|(array, _)| array);
+
+        let mut req_values = vec![];
+        for field in fields.chain(arrays) {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            let field_value = match self.array_slots.iter().find(|(array, _)| array == field) {
+                Some((_, slots)) => {
+                    let slot_values = slots.iter().map(|slot| value(slot));
+
+                    quote! { [#(#slot_values),*] }
+                }
+                None if self
+                    .required_options
+                    .iter()
+                    .any(|(_, inner)| inner == field) =>
+                {
+                    let field_value = value(field);
+
+                    quote! { Some(#field_value) }
+                }
+                None => value(field),
+            };
+
+            req_values.push(quote! { #(#cfgs)* #field_ident: #field_value });
+        }
+
+        req_values
+    }
+
+    // Returns the const generic arguments of the builder in the state where all the required fields are set.
+    // The optional fields with `#[builder(once)]` are not set yet, so their setters can still be called.
+    fn all_set(&self) -> Vec<proc_macro2::TokenStream> {
+        (0..self.b_const_pn.len())
+            .map(|index| {
+                let is_req = index < self.req_fields.len();
+
+                quote! { #is_req }
+            })
+            .collect()
+    }
+
+    // Returns `with_required` which creates a builder from the values of all the required fields at once.
+    // The builder is in the same state as if every required setter was called, so it can be built right away.
+    //
+    // Arrays with `#[builder(per_index)]` are taken as a whole and split into their elements.
+    pub fn with_required(&self) -> proc_macro2::TokenStream {
+        let mut params = vec![];
+        let mut splits = vec![];
+
+        for field in &self.req_fields {
+            let is_slot = self
+                .array_slots
+                .iter()
+                .any(|(_, slots)| slots.contains(field));
+
+            if !is_slot {
+                let field_ident = &field.ident;
+                let field_ty = &field.ty;
+                let cfgs = self.f_attrs[*field].cfgs();
+
+                params.push(quote! { #(#cfgs)* #field_ident: #field_ty });
+            }
+        }
+
+        for (array, slots) in &self.array_slots {
+            let field_ident = &array.ident;
+            let field_ty = &array.ty;
+            let cfgs = self.f_attrs[*array].cfgs();
+            let slot_idents = slots.iter().map(|slot| &slot.ident);
+
+            params.push(quote! { #(#cfgs)* #field_ident: #field_ty });
+            splits.push(quote! { #(#cfgs)* let [#(#slot_idents),*] = #field_ident; });
+        }
+
+        let req_inits = self.req_fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            let store = self.req_store(quote! { #field_ident });
+
+            quote! { #(#cfgs)* #field_ident: #store }
+        });
+
+        // The initialization of the required fields comes first, so skip them.
+        let other_inits = &self.b_inits[self.req_fields.len()..];
+
+        let b_ident = &self.b_ident;
+        let impl_generics = &self.impl_generics;
+        let where_clause = &self.where_clause;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let all_false = &self.all_false;
+        let all_true = self.all_set();
+        let items_cfg = self.items_cfg();
+
+        let (builder_ty, builder_init) = self.builder_fn(
+            quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_true,)* #(#st_type_pn,)*> },
+            quote! {
+                #b_ident {
+                    #(#req_inits,)*
+                    #(#other_inits,)*
+                }
+            },
+        );
+
+        quote! {
+            #items_cfg
+            impl #impl_generics #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_false,)* #(#st_type_pn,)*>
+                #where_clause
+            {
+                #[must_use]
+                pub fn with_required(#(#params),*) -> #builder_ty {
+                    #(#splits)*
+                    #builder_init
+                }
+            }
+        }
+    }
+
+    // Returns the `From` impl which converts the struct back into a builder if requested by `#[builder(into_builder)]`.
+    // The builder is in the same state as if every required setter was called, so it can be changed and built again.
+    pub fn reverse_conversion_trait(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.converts_into_builder() {
+            return None;
+        }
+
+        let s_fields = self.s_fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            quote! { #(#cfgs)* #field_ident }
+        });
+
+        let splits = self.array_slots.iter().map(|(array, slots)| {
+            let field_ident = &array.ident;
+            let cfgs = self.f_attrs[*array].cfgs();
+            let slot_idents = slots.iter().map(|slot| &slot.ident);
+
+            quote! { #(#cfgs)* let [#(#slot_idents),*] = #field_ident; }
+        });
+
+        let req_inits = self.req_fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+            let store = self.req_store(quote! { #field_ident });
+
+            quote! { #(#cfgs)* #field_ident: #store }
+        });
+
+        let opt_inits = self.opt_fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            quote! { #(#cfgs)* #field_ident }
+        });
+
+        // Fields with `default_from` are stored in an `Option` so they're not computed again.
+        let def_inits = self.def_fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[*field].cfgs();
+
+            if self.f_attrs[*field].default_from().is_some() {
+                quote! { #(#cfgs)* #field_ident: Some(#field_ident) }
+            } else {
+                quote! { #(#cfgs)* #field_ident }
+            }
+        });
+
+        let s_ident = &self.s_ident;
+        let b_ident = &self.b_ident;
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let where_clause = &self.where_clause;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let all_true = self.all_set();
+        let items_cfg = self.items_cfg();
+
+        Some(quote! {
+            #items_cfg
+            impl #impl_generics ::core::convert::From<#s_ident #ty_generics>
+                for #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_true,)* #(#st_type_pn,)*>
+                #where_clause
+            {
+                fn from(value: #s_ident #ty_generics) -> Self {
+                    let #s_ident { #(#s_fields),* } = value;
+                    #(#splits)*
+
+                    #b_ident {
+                        #(#req_inits,)*
+                        #(#opt_inits,)*
+                        #(#def_inits,)*
+                    }
+                }
+            }
+        })
+    }
+
+    // Returns the name of the const generic parameter of the builder with the given index.
+    // It's prefixed with underscores until it doesn't collide with a const or type parameter of the struct,
+    // which the builder also has.
+    fn ct_param_ident(&self, index: usize, field: &syn::Field) -> syn::Ident {
+        let taken: Vec<String> = self
+            .st_const_pn
+            .iter()
+            .chain(&self.st_type_pn)
+            .map(|param_name| quote! { #param_name }.to_string())
+            .collect();
+
+        let mut ct_param_name = format!("P{}", index);
+        while taken.contains(&ct_param_name) {
+            ct_param_name.insert(0, '_');
+        }
+
+        syn::Ident::new(&ct_param_name, field.span())
+    }
+
     // Iterates over required fields and initializes the generator.
     pub fn req_init(&mut self) {
         for (index, field) in self.req_fields.iter().enumerate() {
             let field_ident = &field.ident;
             let field_ty = &field.ty;
-            let ct_param_ident = syn::Ident::new(&format!("P{}", index), field.span());
+
+            let ct_param_ident = self.ct_param_ident(index, field);
+            let cfgs = self.f_attrs[field].cfgs();
 
             // Wrap the type of the field in an `Option` to be able to set it to `None` at the beginning.
+            // With `#[builder(storage = maybe_uninit)]`, the const generic parameter tracks whether it's set instead.
+            let storage_ty = self.req_storage_ty(field_ty, &ct_param_ident);
+            let empty = self.req_empty();
             self.b_fields
-                .push(quote! { #field_ident: ::std::option::Option<#field_ty> });
-            self.b_inits.push(quote! { #field_ident: None });
+                .push(quote! { #(#cfgs)* #field_ident: #storage_ty });
+            self.b_inits.push(quote! { #(#cfgs)* #field_ident: #empty });
 
             // Create a const generic parameter for each required field in order to track whether it's been initialized or not.
-            self.b_const_p.push(quote! { const #ct_param_ident: bool });
-            self.b_const_pn.push(quote! { #ct_param_ident });
+            // With `#[builder(mode = runtime)]`, the builder checks them in `try_build` instead.
+            if !self.s_attrs.runtime_mode() {
+                self.b_const_p.push(quote! { const #ct_param_ident: bool });
+                self.b_const_pn.push(quote! { #ct_param_ident });
 
-            self.all_false.push(quote! { false });
+                self.all_false.push(quote! { false });
+            }
 
             self.req_moves
-                .push(quote! { #field_ident: self.#field_ident });
-            self.req_unwraps
-                .push(quote! { #field_ident: self.#field_ident.unwrap_unchecked() });
+                .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
         }
+
+        self.req_unwraps = self.req_values(|field| self.req_take(field));
     }
 
     // Iterates over optional fields and initializes the generator.
@@ -34,13 +280,26 @@ impl<'a> Generator<'a> {
         for opt_field in &self.opt_fields {
             let field_ident = &opt_field.ident;
             let field_ty = &opt_field.ty;
+            let cfgs = self.f_attrs[opt_field].cfgs();
 
             // Since this field is already `Option`, we don't wrap it in `Option` again.
-            self.b_fields.push(quote! { #field_ident: #field_ty });
-            self.b_inits.push(quote! { #field_ident: None });
+            self.b_fields
+                .push(quote! { #(#cfgs)* #field_ident: #field_ty });
+            self.b_inits.push(quote! { #(#cfgs)* #field_ident: None });
 
             self.opt_moves
-                .push(quote! { #field_ident: self.#field_ident });
+                .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
+        }
+
+        // Optional fields with `#[builder(once)]` get a const generic parameter of their own after the ones of
+        // the required fields. It tracks whether the field is set, but doesn't guard the `build` function.
+        for (once_index, field) in self.once_fields.iter().enumerate() {
+            let ct_param_ident = self.ct_param_ident(self.req_fields.len() + once_index, field);
+
+            self.b_const_p.push(quote! { const #ct_param_ident: bool });
+            self.b_const_pn.push(quote! { #ct_param_ident });
+
+            self.all_false.push(quote! { false });
         }
     }
 
@@ -49,18 +308,96 @@ impl<'a> Generator<'a> {
         for field in &self.def_fields {
             let field_ident = &field.ident;
             let field_ty = &field.ty;
+            let cfgs = self.f_attrs[field].cfgs();
+
+            if let Some(default_from) = self.f_attrs[field].default_from() {
+                let s_ident = &self.s_ident;
+                let ty_generics = &self.ty_generics;
+
+                // The value is only known if its setter is called, otherwise it's computed in `build`.
+                self.b_fields
+                    .push(quote! { #(#cfgs)* #field_ident: ::core::option::Option<#field_ty> });
+                self.b_inits.push(quote! { #(#cfgs)* #field_ident: None });
+
+                self.def_moves
+                    .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
+                self.def_builds
+                    .push(quote! { #(#cfgs)* #field_ident: ::core::default::Default::default() });
+                self.def_computes.push(quote! {
+                    #(#cfgs)*
+                    {
+                        built.#field_ident = match self.#field_ident {
+                            Some(value) => value,
+                            None => {
+                                let compute: fn(&#s_ident #ty_generics) -> #field_ty = #default_from;
+                                compute(&built)
+                            }
+                        };
+                    }
+                });
 
-            let default_value = match self.f_attrs[field].is_default().unwrap() {
-                Some(value) => quote! { #value },
-                None => quote! { ::std::default::Default::default() },
+                continue;
+            }
+
+            // The error of a fallible default value is returned from `builder()`.
+            if let Some(try_default) = self.f_attrs[field].try_default() {
+                self.b_fields
+                    .push(quote! { #(#cfgs)* #field_ident: #field_ty });
+                self.b_inits
+                    .push(quote! { #(#cfgs)* #field_ident: (#try_default)? });
+
+                self.def_moves
+                    .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
+                self.def_builds
+                    .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
+
+                continue;
+            }
+
+            let default = self.f_attrs[field].is_default().unwrap();
+
+            // `Default` is only implemented for arrays of up to 32 elements.
+            // So arrays are built element by element to support any length.
+            let default_expr = if let syn::Type::Array(_) = field_ty {
+                quote! { ::core::array::from_fn(|_| ::core::default::Default::default()) }
+            } else {
+                quote! { ::core::default::Default::default() }
+            };
+
+            let default_value = if self.f_attrs[field].once_cell() {
+                // With `once_cell`, the default value is a function that gets called only once.
+                // Its result is then shared across all builders by cloning it.
+                // Other values are wrapped in a closure to be evaluated only once.
+                let init = match default {
+                    Some(Value::Path(path)) => quote! { #path },
+                    Some(value) => quote! { || #value },
+                    None => quote! { || #default_expr },
+                };
+
+                quote! {
+                    {
+                        static CELL: ::std::sync::OnceLock<#field_ty> = ::std::sync::OnceLock::new();
+
+                        ::core::clone::Clone::clone(CELL.get_or_init(#init))
+                    }
+                }
+            } else {
+                match default {
+                    Some(value) => quote! { #value },
+                    None => default_expr,
+                }
             };
 
             // No need to wrap a default field in an `Option` since we have its initialization value.
-            self.b_fields.push(quote! { #field_ident: #field_ty });
-            self.b_inits.push(quote! { #field_ident: #default_value });
+            self.b_fields
+                .push(quote! { #(#cfgs)* #field_ident: #field_ty });
+            self.b_inits
+                .push(quote! { #(#cfgs)* #field_ident: #default_value });
 
             self.def_moves
-                .push(quote! { #field_ident: self.#field_ident });
+                .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
+            self.def_builds
+                .push(quote! { #(#cfgs)* #field_ident: self.#field_ident });
         }
     }
 }
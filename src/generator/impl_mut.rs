@@ -0,0 +1,382 @@
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+use super::Generator;
+use crate::err::Error;
+use crate::wrap::{collection_item, is_option, type_ident};
+
+impl<'a> Generator<'a> {
+    // Returns the setters of a builder which doesn't track which required fields are set in its type.
+    // They take `receiver` and return `self_ty`, which is `&mut self` and `&mut Self` for the mutable builder
+    // and `mut self` and `Self` for the builder of `#[builder(mode = runtime)]`.
+    //
+    // Unlike the setters of the typestate builder, the type of the builder stays the same,
+    // so they can be called in a loop or conditionally without reassigning the builder.
+    fn mut_setters(
+        &self,
+        receiver: proc_macro2::TokenStream,
+        self_ty: proc_macro2::TokenStream,
+    ) -> Result<Vec<proc_macro2::TokenStream>, Error> {
+        let mut mut_setters = vec![];
+
+        let fields = self
+            .req_fields
+            .iter()
+            .chain(&self.opt_fields)
+            .chain(&self.def_fields);
+
+        for &field in fields {
+            let field_ident = &field.ident;
+            let field_ty = &field.ty;
+
+            if self.f_attrs[field].should_skip() && !self.seeded_by_value(field) {
+                continue;
+            }
+
+            let cfgs = self.f_attrs[field].cfgs();
+            let deprecated = self.deprecated_attr(field);
+            let vis = self.setter_vis(field);
+            let trace = self.trace(field);
+            // The value of a field seeded by its `value` can't be replaced.
+            let setter_ident = self
+                .value_setter_ident(field)
+                .filter(|_| !self.seeded_by_value(field));
+            let is_def = self.def_fields.contains(&field);
+
+            // Required and optional fields are stored in an `Option`.
+            // So are default fields with `default_from` since their value is computed in `build`.
+            let is_wrapped = !is_def || self.f_attrs[field].default_from().is_some();
+            let value_ty = is_option(field_ty).unwrap_or(field_ty);
+
+            let (arg_ty, arg_value) = self.setter_arg(field, value_ty)?;
+            let (check, mut arg_value) = self.check_value(field, value_ty, arg_value);
+            if is_wrapped {
+                arg_value = quote! { Some(#arg_value) };
+            }
+
+            let (arg_ty, assign) = self.set_if_some(
+                field,
+                arg_ty,
+                quote! {
+                    #check
+                    self.#field_ident = #arg_value;
+                },
+            );
+            let (ret_ty, ret) = self.checked_ret(field, self_ty.clone(), quote! { self });
+
+            let mut_setter = |setter_ident: &syn::Ident, arg_ty: &proc_macro2::TokenStream| {
+                quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[inline]
+                    #vis fn #setter_ident(#receiver, #field_ident: #arg_ty) -> #ret_ty {
+                        #trace
+                        #assign
+                        #ret
+                    }
+                }
+            };
+
+            // Returns the collection of the field, creating it if the field is wrapped in an `Option`.
+            // Only the repeated setters use it, so only they require the type of the field to be a path.
+            let collection = || -> Result<proc_macro2::TokenStream, Error> {
+                if is_wrapped {
+                    let container_ident = type_ident(value_ty)?;
+
+                    Ok(quote! { self.#field_ident.get_or_insert_with(#container_ident::new) })
+                } else {
+                    Ok(quote! { self.#field_ident })
+                }
+            };
+
+            if let Some(each) = self.f_attrs[field].repeated() {
+                let item_type = collection_item(value_ty)?;
+                let each_ident = syn::Ident::new(each.as_str(), field.span());
+                let collection = collection()?;
+                let on_push = self.on_push(field, quote! { #collection.last() });
+                let (each_ty, item) = self.each_arg(field, &each_ident, &item_type);
+
+                mut_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[inline]
+                    #vis fn #each_ident(#receiver, #each_ident: #each_ty) -> #self_ty {
+                        #trace
+                        #collection.extend(Some(#item));
+                        #on_push
+                        self
+                    }
+                });
+
+                mut_setters.extend(self.each_builder_setter(
+                    field,
+                    &each_ident,
+                    &item_type,
+                    receiver.clone(),
+                    self_ty.clone(),
+                )?);
+            }
+
+            if is_option(field_ty).is_some() {
+                if let Some(replace_ident) = self.replace_ident(field) {
+                    mut_setters.push(quote! {
+                        #(#cfgs)*
+                        #deprecated
+                        #[inline]
+                        #vis fn #replace_ident(#receiver, #field_ident: #field_ty) -> #self_ty {
+                            #trace
+                            self.#field_ident = #field_ident;
+                            self
+                        }
+                    });
+                }
+            }
+
+            if !self.seeded_by_value(field) {
+                for alias_ident in self.alias_idents(field) {
+                    mut_setters.push(mut_setter(&alias_ident, &arg_ty));
+                }
+            }
+
+            // The repeated setter takes precedence if it has the same name as the setter.
+            if let Some(setter_ident) = &setter_ident {
+                mut_setters.push(mut_setter(setter_ident, &arg_ty));
+
+                if let Some((borrowed_ident, borrowed_ty)) =
+                    self.borrowed_setter(field, setter_ident, value_ty)
+                {
+                    mut_setters.push(mut_setter(&borrowed_ident, &borrowed_ty));
+                }
+            }
+
+            if let Some(extend_ident) = self.extend_ident(field) {
+                let item_type = collection_item(value_ty)?;
+                let collection = collection()?;
+
+                mut_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[inline]
+                    #vis fn #extend_ident(#receiver, iter: impl ::core::iter::IntoIterator<Item = #item_type>) -> #self_ty {
+                        #trace
+                        #collection.extend(iter);
+                        self
+                    }
+                });
+            }
+        }
+
+        Ok(mut_setters)
+    }
+
+    // Generate the mutable builder if requested by `#[builder(both_modes)]`.
+    //
+    // The mutable builder has the same fields as the typestate builder, but it doesn't track
+    // which required fields are set in its type. So its `build` function checks them at runtime.
+    pub fn mut_builder(&self) -> Result<Vec<proc_macro2::TokenStream>, Error> {
+        if !self.s_attrs.both_modes() {
+            return Ok(vec![]);
+        }
+
+        let mut_setters = self.mut_setters(quote! { &mut self }, quote! { &mut Self })?;
+        let runtime_build = self.runtime_build(Self::missing_message);
+        let validate_fn = self.validate_fn();
+
+        // Define these to be able to interpolate in quote.
+        let b_mut_ident = format_ident!("{}Mut", self.b_ident);
+        let s_ident = &self.s_ident;
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let extend_trait = self.extend_trait(
+            quote! { #impl_generics },
+            quote! { #b_mut_ident #ty_generics },
+            false,
+        )?;
+        let where_clause = &self.where_clause;
+        let b_fields = &self.b_fields;
+        let b_inits = &self.b_inits;
+        let items_cfg = self.items_cfg();
+        let (builder_mut_ty, builder_mut_init) = self.builder_fn(
+            quote! { #b_mut_ident #ty_generics },
+            quote! { #b_mut_ident { #(#b_inits),* } },
+        );
+
+        Ok(vec![quote! {
+            #items_cfg
+            pub struct #b_mut_ident #impl_generics #where_clause {
+                #(#b_fields),*
+            }
+
+            #items_cfg
+            impl #impl_generics #s_ident #ty_generics #where_clause {
+                #[must_use]
+                pub fn builder_mut() -> #builder_mut_ty {
+                    #builder_mut_init
+                }
+            }
+
+            #items_cfg
+            impl #impl_generics #b_mut_ident #ty_generics #where_clause {
+                #(#mut_setters)*
+                #validate_fn
+
+                #[inline]
+                pub fn build(self) -> ::core::result::Result<#s_ident #ty_generics, &'static str> {
+                    #runtime_build
+                }
+            }
+
+            #extend_trait
+        }])
+    }
+
+    // Returns the error of the `build` functions of the runtime-checked builders when `field` is not set.
+    fn missing_message(field: &syn::Field) -> proc_macro2::TokenStream {
+        let message = format!("missing `{}`", field.ident.as_ref().unwrap());
+
+        quote! { #message }
+    }
+
+    // Returns the body of a `build` function which checks that the required fields are set at runtime.
+    // `missing` returns the error of the function when a required field is not set.
+    pub fn runtime_build(
+        &self,
+        missing: impl Fn(&syn::Field) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        // Move the value of each required field into the struct or fail if it's not set.
+        let req_checks = self.req_values(|field| {
+            let field_ident = &field.ident;
+            let error = missing(field);
+
+            quote! {
+                match self.#field_ident {
+                    Some(value) => value,
+                    None => return ::core::result::Result::Err(#error),
+                }
+            }
+        });
+
+        let s_ident = &self.s_ident;
+        let opt_moves = &self.opt_moves;
+        let def_builds = &self.def_builds;
+        let def_computes = &self.def_computes;
+        let repair = self.repair();
+
+        quote! {
+            #[allow(unused_mut)]
+            let mut built = #s_ident {
+                #(#opt_moves,)*
+                #(#def_builds,)*
+                #(#req_checks,)*
+            };
+
+            #(#def_computes)*
+            #repair
+
+            ::core::result::Result::Ok(built)
+        }
+    }
+
+    // Generate the builder of `#[builder(mode = runtime)]` instead of the typestate builder.
+    //
+    // It has no const generic parameters, so its setters return `Self` no matter which fields are set.
+    // This keeps the signatures short and the compile times low for structs with many required fields.
+    // The only way to build the struct is `try_build`, which checks the required fields at runtime.
+    pub fn runtime_builder(&self) -> Result<proc_macro2::TokenStream, Error> {
+        let setters = self.mut_setters(quote! { mut self }, quote! { Self })?;
+        let runtime_build = self.runtime_build(Self::missing_message);
+
+        let builder_default_trait = self.builder_default_trait();
+        let with_required = self.with_required();
+        let into_builder = self.reverse_conversion_trait();
+        let check_error_type = self.check_error_type();
+        let default_trait = self.default_trait();
+        let clone_trait = self.clone_trait();
+        let debug_trait = self.struct_debug_trait();
+        let partial_fn = self.partial_fn();
+        let apply_fn = self.apply_fn();
+        let validate_fn = self.validate_fn();
+        let pairs_constructor = self.pairs_constructor()?;
+        let build_pairs_fn = self.build_pairs_fn();
+        let partial_type = self.partial_type();
+        let mut_builder = self.mut_builder()?;
+
+        // Define these to be able to interpolate in quote.
+        let b_ident = &self.b_ident;
+        let s_ident = &self.s_ident;
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let where_clause = &self.where_clause;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let b_fields = &self.b_fields;
+        let b_inits = &self.b_inits;
+        let items_cfg = self.items_cfg();
+        let deprecated = self
+            .s_attrs
+            .deprecated()
+            .map(|note| quote! { #[deprecated = #note] });
+        let (builder_ty, builder_init) = self.builder_fn(
+            quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*> },
+            quote! { #b_ident { #(#b_inits),* } },
+        );
+        let from_parts_fn = self.restore_fn(&builder_ty);
+        let extend_trait = self.extend_trait(
+            quote! { <#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*> },
+            quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*> },
+            false,
+        )?;
+
+        Ok(quote! {
+            #items_cfg
+            pub struct #b_ident<#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*> #where_clause {
+                #(#b_fields),*
+            }
+
+            #items_cfg
+            impl #impl_generics #s_ident #ty_generics #where_clause {
+                #[must_use]
+                #deprecated
+                pub fn builder() -> #builder_ty {
+                    #builder_init
+                }
+
+                #from_parts_fn
+            }
+
+            #builder_default_trait
+            #with_required
+            #into_builder
+            #check_error_type
+            #pairs_constructor
+
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*>
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*>
+                #where_clause
+            {
+                #(#setters)*
+                #partial_fn
+                #apply_fn
+                #validate_fn
+                #build_pairs_fn
+
+                #[inline]
+                pub fn try_build(self) -> ::core::result::Result<#s_ident #ty_generics, &'static str> {
+                    #runtime_build
+                }
+            }
+
+            #extend_trait
+            #(#default_trait)*
+            #(#clone_trait)*
+            #(#debug_trait)*
+            #partial_type
+            #(#mut_builder)*
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use quote::{format_ident, quote, ToTokens};
+
+use super::Generator;
+use crate::err::Error;
+use crate::wrap::is_option;
+
+impl<'a> Generator<'a> {
+    // Returns the name of the error of `from_pairs`.
+    fn pairs_error_ident(&self) -> syn::Ident {
+        format_ident!("{}PairsError", self.b_ident)
+    }
+
+    // Returns the fields that `from_pairs` can set, along with the type of their values.
+    fn pairs_fields(&self) -> Vec<(&'a syn::Field, &'a syn::Type)> {
+        let req_fields = self.req_fields.iter().map(|&field| (field, &field.ty));
+        let opt_fields = self
+            .opt_fields
+            .iter()
+            .map(|&field| (field, is_option(&field.ty).unwrap()));
+        let def_fields = self.def_fields.iter().map(|&field| (field, &field.ty));
+
+        req_fields
+            .chain(opt_fields)
+            .chain(def_fields)
+            .filter(|(field, _)| !self.f_attrs[*field].should_skip())
+            .collect()
+    }
+
+    // Generate the `from_pairs` function of the struct if requested by `#[builder(from_pairs)]`, along with its error.
+    //
+    // It sets the fields of a builder by matching the names in the pairs against the names of the fields at runtime,
+    // so the fields must all have the same type. The values are stored as is, without going through the setters.
+    // Then the builder is built by `build_pairs`, which checks that the required fields are set.
+    pub fn pairs_constructor(&self) -> Result<Option<proc_macro2::TokenStream>, Error> {
+        if !self.s_attrs.builds_from_pairs() {
+            return Ok(None);
+        }
+
+        let fields = self.pairs_fields();
+
+        // A struct without fields to set only accepts unknown names.
+        let value_ty = fields
+            .first()
+            .map_or_else(|| quote! { () }, |(_, ty)| ty.to_token_stream());
+        if let Some((_, ty)) = fields
+            .iter()
+            .find(|(_, ty)| ty.to_token_stream().to_string() != value_ty.to_string())
+        {
+            return Err(Error::FromPairsType((*ty).clone()));
+        }
+
+        let arms = fields.iter().map(|&(field, _)| {
+            let field_ident = &field.ident;
+            let name = field_ident.as_ref().unwrap().to_string();
+            let cfgs = self.f_attrs[field].cfgs();
+
+            // Required and optional fields are stored in an `Option`, and so are default fields with `default_from`.
+            let is_wrapped =
+                !self.def_fields.contains(&field) || self.f_attrs[field].default_from().is_some();
+            let value = if is_wrapped {
+                quote! { Some(value) }
+            } else {
+                quote! { value }
+            };
+
+            quote! {
+                #(#cfgs)*
+                #name => builder.#field_ident = #value
+            }
+        });
+
+        // Define these to be able to interpolate in quote.
+        let error_ident = self.pairs_error_ident();
+        let s_ident = &self.s_ident;
+        let s_vis = self.s_vis;
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let where_clause = &self.where_clause;
+        let items_cfg = self.items_cfg();
+
+        let error_trait = (!self.s_attrs.no_std()).then(|| {
+            quote! {
+                #items_cfg
+                impl ::std::error::Error for #error_ident {}
+            }
+        });
+
+        Ok(Some(quote! {
+            #items_cfg
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #s_vis enum #error_ident {
+                Unknown(&'static str),
+                Missing(&'static str),
+            }
+
+            #items_cfg
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Self::Unknown(name) => ::core::write!(f, "unknown field `{}`", name),
+                        Self::Missing(name) => ::core::write!(f, "missing field `{}`", name),
+                    }
+                }
+            }
+
+            #error_trait
+
+            #items_cfg
+            impl #impl_generics #s_ident #ty_generics #where_clause {
+                #[allow(deprecated)]
+                pub fn from_pairs<__I>(pairs: __I) -> ::core::result::Result<Self, #error_ident>
+                    where __I: ::core::iter::IntoIterator<Item = (&'static str, #value_ty)>
+                {
+                    let mut builder = Self::builder();
+
+                    for (name, value) in pairs {
+                        match name {
+                            #(#arms,)*
+                            _ => return ::core::result::Result::Err(#error_ident::Unknown(name)),
+                        }
+                    }
+
+                    builder.build_pairs()
+                }
+            }
+        }))
+    }
+
+    // Returns the `build_pairs` function of the builder which builds the struct for `from_pairs`.
+    // It's available in every state since the required fields are checked at runtime.
+    pub fn build_pairs_fn(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.builds_from_pairs() {
+            return None;
+        }
+
+        let error_ident = self.pairs_error_ident();
+        let runtime_build = self.runtime_build(|field| {
+            let name = field.ident.as_ref().unwrap().to_string();
+
+            quote! { #error_ident::Missing(#name) }
+        });
+
+        // Define these to be able to interpolate in quote.
+        let s_ident = &self.s_ident;
+        let ty_generics = &self.ty_generics;
+
+        Some(quote! {
+            fn build_pairs(self) -> ::core::result::Result<#s_ident #ty_generics, #error_ident> {
+                #runtime_build
+            }
+        })
+    }
+}
@@ -0,0 +1,366 @@
+use quote::{format_ident, quote, ToTokens};
+
+use super::Generator;
+use crate::generics::{erase_lifetimes, trivial_bound};
+
+impl<'a> Generator<'a> {
+    // Returns the name of the snapshot type of `#[builder(partial)]`.
+    fn partial_ident(&self) -> syn::Ident {
+        format_ident!("Partial{}", self.s_ident)
+    }
+
+    // Returns the type of the field of the snapshot mirroring `field`.
+    // Fields that are already an `Option` keep their type, others are wrapped in one.
+    fn partial_ty(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ty = &field.ty;
+
+        if self.opt_fields.contains(&field)
+            || self
+                .required_options
+                .iter()
+                .any(|(outer, _)| *outer == field)
+        {
+            quote! { #field_ty }
+        } else {
+            quote! { ::core::option::Option<#field_ty> }
+        }
+    }
+
+    // Returns `partial` which clones the values set so far into the snapshot, if requested by `#[builder(partial)]`.
+    //
+    // Default values count as set. Fields with `default_from` are only set if their setter was called,
+    // and arrays with `per_index` are only set if all of their elements are.
+    pub fn partial_fn(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.partial() {
+            return None;
+        }
+
+        let mut values = vec![];
+        let mut clone_tys = vec![];
+        for &field in &self.s_fields {
+            let field_ident = &field.ident;
+            let field_ty = &field.ty;
+            let cfgs = self.f_attrs[field].cfgs();
+
+            let array_slots = self
+                .array_slots
+                .iter()
+                .find(|(array, _)| *array == field)
+                .map(|(_, slots)| slots);
+            let required_option = self
+                .required_options
+                .iter()
+                .find(|(outer, _)| *outer == field)
+                .map(|(_, inner)| *inner);
+
+            let value = if let Some(slots) = array_slots {
+                let slot_idents: Vec<_> = slots.iter().map(|slot| &slot.ident).collect();
+                let slot_refs = slots.iter().map(|slot| self.req_ref(slot));
+                clone_tys.extend(slots.iter().map(|slot| &slot.ty));
+
+                quote! {
+                    match (#(#slot_refs,)*) {
+                        (#(Some(#slot_idents),)*) => Some([#(::core::clone::Clone::clone(#slot_idents)),*]),
+                        _ => None,
+                    }
+                }
+            } else if let Some(inner) = required_option {
+                let inner_ref = self.req_ref(inner);
+                clone_tys.push(&inner.ty);
+
+                quote! { ::core::option::Option::map(#inner_ref, ::core::clone::Clone::clone) }
+            } else if self.req_fields.contains(&field) {
+                let field_ref = self.req_ref(field);
+                clone_tys.push(field_ty);
+
+                quote! { ::core::option::Option::map(#field_ref, ::core::clone::Clone::clone) }
+            } else if self.opt_fields.contains(&field) {
+                clone_tys.push(field_ty);
+
+                quote! { ::core::clone::Clone::clone(&self.#field_ident) }
+            } else if self.f_attrs[field].default_from().is_some() {
+                clone_tys.push(field_ty);
+
+                quote! { ::core::option::Option::map(self.#field_ident.as_ref(), ::core::clone::Clone::clone) }
+            } else {
+                clone_tys.push(field_ty);
+
+                quote! { Some(::core::clone::Clone::clone(&self.#field_ident)) }
+            };
+
+            values.push(quote! { #(#cfgs)* #field_ident: #value });
+        }
+
+        let clone_bounds = clone_bounds(clone_tys);
+        let partial_ident = self.partial_ident();
+        let ty_generics = &self.ty_generics;
+
+        Some(quote! {
+            #[must_use]
+            pub fn partial(&self) -> #partial_ident #ty_generics
+                where #(#clone_bounds),*
+            {
+                #partial_ident {
+                    #(#values,)*
+                }
+            }
+        })
+    }
+
+    // Returns `from_parts` which restores a builder from a snapshot taken by `partial`, if requested by `#[builder(partial)]`.
+    //
+    // The typestate builder it returns is the one of `builder()`, so only the optional and default fields are restored,
+    // and the required fields and the ones with `once` must be set again. The builder of `mode = runtime` has no state,
+    // so every field is restored. Skipped fields keep their value since they can't be set.
+    pub fn restore_fn(
+        &self,
+        builder_ty: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.partial() {
+            return None;
+        }
+
+        let restores_required = self.s_attrs.runtime_mode();
+        let mut restores = vec![];
+        for &field in &self.s_fields {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[field].cfgs();
+
+            if self.f_attrs[field].should_skip() {
+                continue;
+            }
+
+            let array_slots = self
+                .array_slots
+                .iter()
+                .find(|(array, _)| *array == field)
+                .map(|(_, slots)| slots);
+            let required_option = self
+                .required_options
+                .iter()
+                .find(|(outer, _)| *outer == field)
+                .map(|(_, inner)| *inner);
+
+            let restore = if let Some(slots) = array_slots {
+                if !restores_required {
+                    continue;
+                }
+
+                let slot_idents: Vec<_> = slots.iter().map(|slot| &slot.ident).collect();
+                let slot_stores = slot_idents
+                    .iter()
+                    .map(|slot_ident| self.req_store(quote! { #slot_ident }));
+
+                quote! {
+                    if let Some([#(#slot_idents),*]) = parts.#field_ident {
+                        #(builder.#slot_idents = #slot_stores;)*
+                    }
+                }
+            } else if let Some(inner) = required_option {
+                if !restores_required {
+                    continue;
+                }
+
+                let inner_ident = &inner.ident;
+                quote! { builder.#inner_ident = parts.#field_ident; }
+            } else if self.req_fields.contains(&field) {
+                if !restores_required {
+                    continue;
+                }
+
+                quote! { builder.#field_ident = parts.#field_ident; }
+            } else if self.once_fields.contains(&field) {
+                continue;
+            } else if self.opt_fields.contains(&field)
+                || self.f_attrs[field].default_from().is_some()
+            {
+                quote! { builder.#field_ident = parts.#field_ident; }
+            } else {
+                quote! {
+                    if let Some(value) = parts.#field_ident {
+                        builder.#field_ident = value;
+                    }
+                }
+            };
+
+            restores.push(quote! {
+                #(#cfgs)*
+                #restore
+            });
+        }
+
+        // With a fallible default value, `builder()` returns a `Result` and so does `from_parts`.
+        // The type given by the callers is the return type of `builder()`, so it's already wrapped.
+        let return_value = match self.builder_error() {
+            Some(_) => quote! { ::core::result::Result::Ok(builder) },
+            None => quote! { builder },
+        };
+        let question_mark = self.builder_error().map(|_| quote! { ? });
+        let partial_ident = self.partial_ident();
+        let ty_generics = &self.ty_generics;
+
+        Some(quote! {
+            #[must_use]
+            #[allow(deprecated, unused_mut, unused_variables)]
+            pub fn from_parts(parts: #partial_ident #ty_generics) -> #builder_ty {
+                let mut builder = Self::builder()#question_mark;
+                #(#restores)*
+
+                #return_value
+            }
+        })
+    }
+
+    // Returns `build_keep` which builds the struct out of clones of the values of the builder, so the builder
+    // can be used to build more variants of the struct. Like `build`, it's only available once all the required fields are set.
+    pub fn build_keep_fn(&self, guard_trait_idents: &[syn::Ident]) -> proc_macro2::TokenStream {
+        let mut clone_tys = vec![];
+        let mut values = self.req_values(|field| self.req_clone(field));
+        let mut computes = vec![];
+        clone_tys.extend(self.req_fields.iter().map(|field| &field.ty));
+
+        for &field in self.opt_fields.iter().chain(&self.def_fields) {
+            let field_ident = &field.ident;
+            let field_ty = &field.ty;
+            let cfgs = self.f_attrs[field].cfgs();
+            clone_tys.push(field_ty);
+
+            // The value of a field with `default_from` is computed from the built struct unless it's set.
+            if let Some(default_from) = self.f_attrs[field].default_from() {
+                let s_ident = &self.s_ident;
+                let ty_generics = &self.ty_generics;
+
+                values.push(quote! { #(#cfgs)* #field_ident: ::core::default::Default::default() });
+                computes.push(quote! {
+                    #(#cfgs)*
+                    {
+                        built.#field_ident = match &self.#field_ident {
+                            Some(value) => ::core::clone::Clone::clone(value),
+                            None => {
+                                let compute: fn(&#s_ident #ty_generics) -> #field_ty = #default_from;
+                                compute(&built)
+                            }
+                        };
+                    }
+                });
+            } else {
+                values.push(quote! { #(#cfgs)* #field_ident: ::core::clone::Clone::clone(&self.#field_ident) });
+            }
+        }
+
+        let clone_bounds = clone_bounds(clone_tys);
+        let s_ident = &self.s_ident;
+        let ty_generics = &self.ty_generics;
+        let repair = self.repair();
+
+        quote! {
+            #[must_use]
+            fn build_keep(&self) -> #s_ident #ty_generics
+                where
+                    Self: #(#guard_trait_idents)+*,
+                    #(#clone_bounds),*
+            {
+                #[allow(unused_mut)]
+                let mut built = unsafe {
+                    #s_ident {
+                        #(#values,)*
+                    }
+                };
+
+                #(#computes)*
+                #repair
+
+                built
+            }
+        }
+    }
+
+    // Generate the snapshot type returned by `partial` if requested by `#[builder(partial)]`.
+    //
+    // It mirrors the struct, but each field is an `Option` which is `None` until the field is set.
+    // Its `Debug` implementation redacts the fields with `#[builder(secret)]` like the one of `#[builder(struct_debug)]`.
+    pub fn partial_type(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.partial() {
+            return None;
+        }
+
+        let mut fields = vec![];
+        let mut debug_fields = vec![];
+        let mut debug_bounds = vec![];
+        for &field in &self.s_fields {
+            let field_ident = &field.ident;
+            let field_vis = &field.vis;
+            let field_name = field_ident.as_ref().unwrap().to_string();
+            let partial_ty = self.partial_ty(field);
+            let cfgs = self.f_attrs[field].cfgs();
+
+            fields.push(quote! { #(#cfgs)* #field_vis #field_ident: #partial_ty });
+
+            if self.f_attrs[field].secret() {
+                debug_fields.push(quote! {
+                    #(#cfgs)*
+                    debug.field(#field_name, &::core::format_args!("<redacted>"));
+                });
+            } else {
+                debug_fields.push(quote! {
+                    #(#cfgs)*
+                    debug.field(#field_name, &self.#field_ident);
+                });
+                debug_bounds.push(trivial_bound(&partial_ty, quote! { ::core::fmt::Debug }));
+            }
+        }
+
+        // Define these to be able to interpolate in quote.
+        let partial_ident = self.partial_ident();
+        let partial_name = partial_ident.to_string();
+        let s_vis = self.s_vis;
+        let impl_generics = &self.impl_generics;
+        let ty_generics = &self.ty_generics;
+        let where_clause = &self.where_clause;
+        let predicates: Vec<_> = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .collect();
+        let items_cfg = self.items_cfg();
+
+        Some(quote! {
+            #items_cfg
+            #s_vis struct #partial_ident #impl_generics #where_clause {
+                #(#fields),*
+            }
+
+            #items_cfg
+            impl #impl_generics ::core::fmt::Debug for #partial_ident #ty_generics
+                where
+                    #(#predicates,)*
+                    #(#debug_bounds,)*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut debug = f.debug_struct(#partial_name);
+                    #(#debug_fields)*
+                    debug.finish()
+                }
+            }
+        })
+    }
+}
+
+// Returns the `Clone` bounds of the types in `tys`.
+// Fields whose types only differ in their lifetimes would make the bounds ambiguous, so they're deduplicated.
+fn clone_bounds<'a>(tys: impl IntoIterator<Item = &'a syn::Type>) -> Vec<proc_macro2::TokenStream> {
+    let mut clone_bounds: Vec<proc_macro2::TokenStream> = vec![];
+    for ty in tys {
+        let ty = erase_lifetimes(ty.to_token_stream());
+        let bound = trivial_bound(ty, quote! { ::core::clone::Clone });
+
+        if !clone_bounds
+            .iter()
+            .any(|b| b.to_string() == bound.to_string())
+        {
+            clone_bounds.push(bound);
+        }
+    }
+
+    clone_bounds
+}
@@ -1,11 +1,454 @@
-use quote::quote;
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 
 use super::Generator;
 use crate::err::Error;
-use crate::wrap::{is_option, type_ident, wrapped_in};
+use crate::wrap::{
+    collection_item, has_no_drop_glue, is_cow, is_option, is_smart_pointer, is_string, type_ident,
+};
 
 impl<'a> Generator<'a> {
+    // Returns the name of the setter of `field`.
+    // Defaults to the name of the field, prefixed by the `#[builder(setter_prefix = "prefix")]` of the struct.
+    // An explicit `#[builder(name = "name")]` is used as is.
+    pub fn setter_ident(&self, field: &syn::Field) -> syn::Ident {
+        self.affixed_setter_ident(field, "", "")
+    }
+
+    // Returns the name of the setter of `field` with `prefix` and `suffix` added to it, e.g. `_extend`.
+    // Unless the setter is explicitly named, the name is converted to the case of `#[builder(rename_all = "case")]`.
+    pub fn affixed_setter_ident(
+        &self,
+        field: &syn::Field,
+        prefix: &str,
+        suffix: &str,
+    ) -> syn::Ident {
+        if let Some(name) = self.f_attrs[field].name() {
+            return syn::Ident::new(&format!("{}{}{}", prefix, name, suffix), field.span());
+        }
+
+        self.renamed(&format!(
+            "{}{}{}{}",
+            prefix,
+            self.setter_prefix(),
+            field.ident.as_ref().unwrap().unraw(),
+            suffix
+        ))
+    }
+
+    // Returns the prefix of the setters given by `#[builder(setter_prefix = "prefix")]`.
+    // With `#[builder(setter_prefix = type)]`, it's the name of the struct in snake case followed by `_`.
+    fn setter_prefix(&self) -> String {
+        match self.s_attrs.setter_prefix() {
+            Some(Some(prefix)) => prefix.clone(),
+            Some(None) => format!("{}_", self.s_ident.to_string().to_case(Case::Snake)),
+            None => String::new(),
+        }
+    }
+
+    // Converts `name` to the case of `#[builder(rename_all = "case")]`, if any.
+    // The names are built from the fields without their `r#`, so a name that's a keyword becomes a raw identifier again.
+    pub fn renamed(&self, name: &str) -> syn::Ident {
+        let name = match self.s_attrs.rename_all() {
+            Some(case) => name.to_case(case),
+            None => name.to_string(),
+        };
+
+        // `syn` doesn't parse keywords as identifiers. The ones that can't be raw, like `self`, get a trailing `_` instead.
+        match syn::parse_str::<syn::Ident>(&name) {
+            Ok(ident) => ident,
+            Err(_) => syn::parse_str::<syn::Ident>(&format!("r#{}", name))
+                .unwrap_or_else(|_| format_ident!("{}_", name)),
+        }
+    }
+
+    // Returns the name of the setter that sets the whole value of `field`,
+    // or `None` if the repeated setter has the same name since Rust doesn't support function overloading.
+    // With `#[builder(keep_collection_setter)]`, the setter is kept under the name followed by `_all` instead.
+    pub fn value_setter_ident(&self, field: &syn::Field) -> Option<syn::Ident> {
+        let setter_ident = self.setter_ident(field);
+        let attrs = &self.f_attrs[field];
+
+        match attrs.repeated() {
+            Some(each) if setter_ident == each => attrs
+                .keep_collection_setter()
+                .then(|| self.affixed_setter_ident(field, "", "_all")),
+            _ => Some(setter_ident),
+        }
+    }
+
+    // Returns the `#[deprecated]` attribute of the setters of `field` if it has `#[builder(deprecated = "note")]`.
+    pub fn deprecated_attr(&self, field: &syn::Field) -> Option<proc_macro2::TokenStream> {
+        let note = self.f_attrs[field].deprecated()?;
+
+        Some(quote! { #[deprecated = #note] })
+    }
+
+    // Returns the visibility of the setters of `field`, which is `pub` unless it has `#[builder(vis = "pub(crate)")]`.
+    pub fn setter_vis(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        match self.f_attrs[field].vis() {
+            Some(vis) => quote! { #vis },
+            None => quote! { pub },
+        }
+    }
+
+    // Returns the aliases of the setter of `field` given by `#[builder(name = [name, alias])]` or `#[builder(alias = alias)]`.
+    // They're setters of their own which behave exactly like the setter, so they also move the builder to the same state.
+    // An alias is omitted if the repeated setter has the same name.
+    pub fn alias_idents(&self, field: &syn::Field) -> Vec<syn::Ident> {
+        let attrs = &self.f_attrs[field];
+
+        attrs
+            .aliases()
+            .into_iter()
+            .filter(|alias| attrs.repeated() != Some(*alias))
+            .map(|alias| syn::Ident::new(alias, field.span()))
+            .collect()
+    }
+
+    // Returns the name of the setter that extends the collection of `field` using an iterator.
+    // It's only generated for fields with a repeated setter, or an explicit `#[builder(extend = "name")]`.
+    // Defaults to the name of the setter of the field followed by `_extend`.
+    pub fn extend_ident(&self, field: &syn::Field) -> Option<syn::Ident> {
+        let attrs = &self.f_attrs[field];
+
+        match attrs.extend() {
+            Some(extend) => Some(syn::Ident::new(extend, field.span())),
+            None => attrs
+                .repeated()
+                .map(|_| self.affixed_setter_ident(field, "", "_extend")),
+        }
+    }
+
+    // Returns whether `field` has a `value` but also a repeated setter or an extend setter, like
+    // `#[builder(value = vec![1, 2], each = "arg")]`. The field starts with the value, and only gets the setters
+    // that append to it since the value itself can't be replaced.
+    pub fn seeded_by_value(&self, field: &syn::Field) -> bool {
+        let attrs = &self.f_attrs[field];
+
+        self.def_fields.contains(&field)
+            && attrs.should_skip()
+            && (attrs.repeated().is_some() || attrs.extend().is_some())
+    }
+
+    // Returns the name of the setter that replaces the whole `Option` of an optional field with a repeated setter.
+    // Unlike the other setters, it can clear what the repeated setter accumulated by passing `None`.
+    // It's named `set_` followed by the name of the field, and is omitted if another setter has the same name.
+    pub fn replace_ident(&self, field: &syn::Field) -> Option<syn::Ident> {
+        let each = self.f_attrs[field].repeated()?;
+        let replace_ident = self.renamed(&format!("set_{}", field.ident.as_ref().unwrap().unraw()));
+
+        let taken = replace_ident == each
+            || self.value_setter_ident(field).as_ref() == Some(&replace_ident)
+            || self.extend_ident(field).as_ref() == Some(&replace_ident);
+
+        (!taken).then_some(replace_ident)
+    }
+
+    // Returns the name of the setter of a field with `#[builder(flatten)]`, which configures the builder of the field
+    // using a closure, along with the `{Builder}Init` alias and the `{Builder}Complete` trait of that builder.
+    // The setter is named after the setter of the field followed by `_with`.
+    pub fn flatten_setter(&self, field: &syn::Field) -> Option<(syn::Ident, syn::Path, syn::Path)> {
+        if !self.f_attrs[field].flatten() {
+            return None;
+        }
+
+        let (init_path, complete_path) = builder_paths(&field.ty)?;
+        let with_ident = self.affixed_setter_ident(field, "", "_with");
+
+        Some((with_ident, init_path, complete_path))
+    }
+
+    // Returns the setter of a field with `#[builder(each_builder)]`, which configures the builder of an item of type `item_ty`
+    // using a closure and pushes the built item using the repeated setter `each_ident`. So it returns what the repeated setter returns,
+    // which is `ret_ty`. It's named after the repeated setter followed by `_with`.
+    pub fn each_builder_setter(
+        &self,
+        field: &syn::Field,
+        each_ident: &syn::Ident,
+        item_ty: &syn::Type,
+        receiver: proc_macro2::TokenStream,
+        ret_ty: proc_macro2::TokenStream,
+    ) -> Result<Option<proc_macro2::TokenStream>, Error> {
+        if !self.f_attrs[field].each_builder() {
+            return Ok(None);
+        }
+
+        let (init_path, complete_path) =
+            builder_paths(item_ty).ok_or_else(|| Error::EachBuilderConflict(field.clone()))?;
+        let with_ident = format_ident!("{}_with", each_ident);
+        let cfgs = self.f_attrs[field].cfgs();
+        let deprecated = self.deprecated_attr(field);
+        let vis = self.setter_vis(field);
+        // Like its other setters, the ones of the typestate builder taking `self` are `#[must_use]`, unlike the ones of `mut_setters`.
+        let must_use = (receiver.to_string() == "self").then(|| quote! { #[must_use] });
+
+        Ok(Some(quote! {
+            #(#cfgs)*
+            #deprecated
+            #must_use
+            #[inline]
+            #[allow(deprecated)]
+            #vis fn #with_ident<__B>(#receiver, f: impl ::core::ops::FnOnce(#init_path) -> __B) -> #ret_ty
+                where __B: #complete_path<Output = #item_ty>
+            {
+                self.#each_ident(#complete_path::build(f(<#item_ty>::builder())))
+            }
+        }))
+    }
+
+    // Returns whether the setters of `field` take an `impl Into` of the type they would take otherwise.
+    // `#[builder(into)]` on the struct applies to every field without `#[builder(no_into)]` or `#[builder(to_string)]`,
+    // and so do `Cow` fields, which then take both borrowed and owned values.
+    pub fn takes_into(&self, field: &syn::Field) -> bool {
+        let attrs = &self.f_attrs[field];
+        let is_cow = is_cow(is_option(&field.ty).unwrap_or(&field.ty));
+
+        attrs.into()
+            || ((self.s_attrs.setters_into() || is_cow) && !attrs.no_into() && !attrs.to_string())
+    }
+
+    // Returns whether the setter of `field` taking a value of type `ty` takes a `&str` instead, which it turns into
+    // a `String` using `to_owned`. `#[builder(str_setters)]` on the struct applies to the values that are exactly a `String`,
+    // unless the field converts them in another way using `into`, `to_string` or `wrap`.
+    pub fn takes_str(&self, field: &syn::Field, ty: &syn::Type) -> bool {
+        let attrs = &self.f_attrs[field];
+
+        self.s_attrs.str_setters()
+            && is_string(ty)
+            && !self.takes_into(field)
+            && !attrs.to_string()
+            && !attrs.wrap()
+    }
+
+    // Returns `const` if the setter of `field` taking a value of type `ty` is a `const fn`, which requires `#[builder(const)]` on the struct.
+    // Only the setters that assign their argument as is can be `const`, so the ones converting it using `into`,
+    // `to_string`, `to_owned` or `wrap`, checking it, or pushing to it using `each` or `extend` are not.
+    // Assigning drops the previous value, so neither are the setters of values that may need to be dropped.
+    pub fn constness(
+        &self,
+        field: &syn::Field,
+        ty: &syn::Type,
+    ) -> Option<proc_macro2::TokenStream> {
+        let attrs = &self.f_attrs[field];
+
+        let assigns = !self.takes_into(field)
+            && !self.takes_str(field, ty)
+            && !attrs.to_string()
+            && !attrs.wrap()
+            && !attrs.once_cell()
+            && !attrs.set_if_some()
+            && !attrs.checked()
+            && attrs.repeated().is_none()
+            && attrs.extend().is_none()
+            && has_no_drop_glue(ty);
+
+        // The setters moving the builder into another state leave the previous value of the field in `self`,
+        // and a partially moved builder can only be dropped in a `const fn` if none of its values needs to be dropped.
+        let moves_state = self.req_fields.contains(&field) || self.once_fields.contains(&field);
+        let leaves_droppable = moves_state
+            && self
+                .s_fields
+                .iter()
+                .any(|field| !self.f_attrs[field].should_skip() && !has_no_drop_glue(&field.ty));
+
+        (self.s_attrs.const_setters() && !leaves_droppable && !self.s_attrs.trace() && assigns)
+            .then(|| quote! { const })
+    }
+
+    // Returns the type of the argument of the setter of `field`, and the expression that turns
+    // the argument into a value of type `ty`.
+    //
+    // With `#[builder(setter = wrap)]` and `ty` being `Arc<T>`, the setter takes an `impl Into<Arc<T>>`,
+    // so it takes a `T` and wraps it, but also works for unsized types like `Arc<str>` which are converted from a `&str`.
+    // Otherwise, the setter takes a `ty` as is.
+    // With `#[builder(into)]` on the field or the struct, the setter takes an `impl Into` of the type it would take otherwise.
+    // With `#[builder(to_string)]`, the setter takes an `impl Display` and converts it using `ToString` instead.
+    // With `#[builder(str_setters)]` on the struct and `ty` being `String`, the setter takes a `&str` and converts it using `ToOwned`.
+    pub fn setter_arg(
+        &self,
+        field: &syn::Field,
+        ty: &syn::Type,
+    ) -> Result<(proc_macro2::TokenStream, proc_macro2::TokenStream), Error> {
+        let field_ident = &field.ident;
+        let attrs = &self.f_attrs[field];
+
+        if attrs.to_string() && attrs.into() {
+            return Err(Error::ToStringInto(field.clone()));
+        }
+
+        let into = self.takes_into(field);
+        let str_arg = self.takes_str(field, ty);
+
+        let (arg_ty, mut arg_value) = if attrs.wrap() {
+            let inner_ty =
+                is_smart_pointer(ty).ok_or_else(|| Error::NotSmartPointer(ty.clone()))?;
+
+            (inner_ty, quote! { #field_ident })
+        } else {
+            (ty, quote! { #field_ident })
+        };
+
+        if into {
+            arg_value = quote! { ::core::convert::Into::<#arg_ty>::into(#arg_value) };
+        }
+
+        if attrs.to_string() {
+            let alloc = self.alloc();
+            arg_value = quote! { #alloc::string::ToString::to_string(&#arg_value) };
+        }
+
+        if str_arg {
+            let alloc = self.alloc();
+            arg_value = quote! { #alloc::borrow::ToOwned::to_owned(#arg_value) };
+        }
+
+        if attrs.wrap() {
+            arg_value = quote! { ::core::convert::Into::<#ty>::into(#arg_value) };
+        }
+
+        if into {
+            Ok((quote! { impl ::core::convert::Into<#arg_ty> }, arg_value))
+        } else if attrs.to_string() {
+            Ok((quote! { impl ::core::fmt::Display }, arg_value))
+        } else if str_arg {
+            Ok((quote! { &str }, arg_value))
+        } else if attrs.wrap() {
+            Ok((quote! { impl ::core::convert::Into<#ty> }, arg_value))
+        } else {
+            Ok((quote! { #arg_ty }, arg_value))
+        }
+    }
+
+    // Returns the type of the argument of the repeated setter of `field` pushing items of type `item_ty`,
+    // and the expression of the item to push. With `#[builder(into)]` on the field or the struct,
+    // the repeated setter takes an `impl Into` of the item, e.g. a `&str` for a `Vec<String>`.
+    pub fn each_arg(
+        &self,
+        field: &syn::Field,
+        each_ident: &syn::Ident,
+        item_ty: &syn::Type,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        if self.takes_into(field) {
+            (
+                quote! { impl ::core::convert::Into<#item_ty> },
+                quote! { ::core::convert::Into::<#item_ty>::into(#each_ident) },
+            )
+        } else {
+            (quote! { #item_ty }, quote! { #each_ident })
+        }
+    }
+
+    // Returns the name and the type of the argument of the extra setter that `#[builder(into)]`
+    // generates for `String` and `PathBuf` fields. It takes a `&str` or a `&Path` and is named
+    // after the setter followed by `_str` or `_path`. Since its argument is not generic,
+    // callers never need to annotate the type of what they pass, e.g. the result of `as_ref()`.
+    pub fn borrowed_setter(
+        &self,
+        field: &syn::Field,
+        setter_ident: &syn::Ident,
+        ty: &syn::Type,
+    ) -> Option<(syn::Ident, proc_macro2::TokenStream)> {
+        let attrs = &self.f_attrs[field];
+
+        if !self.takes_into(field) {
+            return None;
+        }
+
+        let ty = if attrs.wrap() {
+            is_smart_pointer(ty)?
+        } else {
+            ty
+        };
+
+        let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+            return None;
+        };
+
+        let last = path.segments.last()?;
+        if !last.arguments.is_empty() {
+            return None;
+        }
+
+        let (suffix, borrowed_ty) = if last.ident == "String" {
+            ("str", quote! { &str })
+        } else if last.ident == "PathBuf" {
+            ("path", quote! { &::std::path::Path })
+        } else {
+            return None;
+        };
+
+        let borrowed_ty = if attrs.set_if_some() {
+            quote! { ::core::option::Option<#borrowed_ty> }
+        } else {
+            borrowed_ty
+        };
+
+        let borrowed_ident = format!("{}_{}", setter_ident, suffix);
+        let borrowed_ident = match attrs.name() {
+            Some(_) => syn::Ident::new(&borrowed_ident, field.span()),
+            None => self.renamed(&borrowed_ident),
+        };
+
+        Some((borrowed_ident, borrowed_ty))
+    }
+
+    // Returns the type of the argument of the setter of `field` and the statement that sets its value.
+    //
+    // With `#[builder(setter = set_if_some)]`, the setter takes an `Option` of `arg_ty`
+    // and only runs `assign` if it's `Some`. Otherwise, they're returned as is.
+    pub fn set_if_some(
+        &self,
+        field: &syn::Field,
+        arg_ty: proc_macro2::TokenStream,
+        assign: proc_macro2::TokenStream,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        if !self.f_attrs[field].set_if_some() {
+            return (arg_ty, assign);
+        }
+
+        let field_ident = &field.ident;
+
+        (
+            quote! { ::core::option::Option<#arg_ty> },
+            quote! {
+                if let Some(#field_ident) = #field_ident {
+                    #assign
+                }
+            },
+        )
+    }
+
+    // Returns the statement that logs setting the value of `field` if the struct has `#[builder(trace)]`.
+    // The crate using the builder must depend on the `log` crate.
+    pub fn trace(&self, field: &syn::Field) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.trace() {
+            return None;
+        }
+
+        let message = format!("{}: set `{}`", self.b_ident, field.ident.as_ref().unwrap());
+
+        Some(quote! { ::log::debug!(#message); })
+    }
+
+    // Returns the statement that calls the `#[builder(on_push = hook)]` of `field` with the last pushed item.
+    // `last` is the expression returning a reference to the last item of the collection, if any.
+    pub fn on_push(
+        &self,
+        field: &syn::Field,
+        last: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        let hook = self.f_attrs[field].on_push()?;
+
+        Some(quote! {
+            if let Some(item) = #last {
+                (#hook)(item);
+            }
+        })
+    }
+
     // Iterates over required fields and generate their corrosponding setters.
     pub fn req_setters(&self) -> Result<Vec<proc_macro2::TokenStream>, Error> {
         let mut req_setters = vec![];
@@ -18,7 +461,24 @@ impl<'a> Generator<'a> {
                 return Err(Error::SkipRequired(req_field.clone()));
             }
 
+            // The state can't depend on whether the value is `Some`.
+            if self.f_attrs[req_field].set_if_some() {
+                return Err(Error::SetIfSomeRequired(req_field.clone()));
+            }
+
             let repeated_attr = self.f_attrs[req_field].repeated();
+            let cfgs = self.f_attrs[req_field].cfgs();
+            let deprecated = self.deprecated_attr(req_field);
+            let vis = self.setter_vis(req_field);
+            let trace = self.trace(req_field);
+            let setter_ident = self.value_setter_ident(req_field);
+            let (arg_ty, arg_value) = self.setter_arg(req_field, field_ty)?;
+            let (check, arg_value) = self.check_value(req_field, field_ty, arg_value);
+            let store = self.req_store(arg_value);
+            // The empty `MaybeUninit` storage left in `self` has a `Drop` implementation, which can't run in a `const fn`.
+            let constness = self
+                .constness(req_field, field_ty)
+                .filter(|_| !self.s_attrs.maybe_uninit());
 
             // When setting a required field, we need to move the other required fields
             // into the new state. So we pick the moves before and after this field.
@@ -36,49 +496,69 @@ impl<'a> Generator<'a> {
             let st_lifetime_pn = &self.st_lifetime_pn;
             let st_const_pn = &self.st_const_pn;
             let st_type_pn = &self.st_type_pn;
-            let req_moves = &self.req_moves;
             let opt_moves = &self.opt_moves;
             let def_moves = &self.def_moves;
+            let extended_move = self.req_extended_move(req_field, index);
 
-            // When we set the value of a required field, we must change to a state in
-            // which the parameter corresponding to that field is set to `true`.
-            // This is the non-repeated setter.
-            let req_setter = quote! {
-                pub fn #field_ident(self, #field_ident: #field_ty) ->
+            // With a `check`, the setter returns a `Result` of the next state.
+            let (ret_ty, ret) = self.checked_ret(
+                req_field,
+                quote! {
                     #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* true, #(#after_pn,)* #(#st_type_pn,)*>
-                {
+                },
+                quote! {
                     #b_ident {
                         #(#before_req_moves,)*
-                        #field_ident: Some(#field_ident),
+                        #field_ident: #store,
                         #(#after_req_moves,)*
                         #(#opt_moves,)*
                         #(#def_moves,)*
                     }
+                },
+            );
+
+            // When we set the value of a required field, we must change to a state in
+            // which the parameter corresponding to that field is set to `true`.
+            // This is the non-repeated setter.
+            let req_setter = |setter_ident: &syn::Ident, arg_ty: &proc_macro2::TokenStream| {
+                quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis #constness fn #setter_ident(self, #field_ident: #arg_ty) -> #ret_ty {
+                        #trace
+                        #check
+                        #ret
+                    }
                 }
             };
 
             if let Some(each) = repeated_attr {
+                let item_type = collection_item(field_ty)?;
                 let container_ident = type_ident(field_ty)?;
-                let item_type = wrapped_in(field_ty, Some("Vec"));
                 let each_ident = syn::Ident::new(each.as_str(), req_field.span());
+                let (each_ty, item) = self.each_arg(req_field, &each_ident, &item_type);
+                let on_push = self.on_push(req_field, self.req_last(req_field));
+                let extend =
+                    self.req_extend(req_field, index, container_ident, quote! { Some(#item) });
 
                 req_setters.push(
                     quote! {
-                        pub fn #each_ident(mut self, #each_ident: #item_type) ->
+                        #(#cfgs)*
+                        #deprecated
+                        #[must_use]
+                        #[inline]
+                        #vis fn #each_ident(mut self, #each_ident: #each_ty) ->
                             #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* true, #(#after_pn,)* #(#st_type_pn,)*>
                         {
-                            match self.#field_ident.as_mut() {
-                                // If the vector is already created, just extend it using the newly provided value.
-                                Some(c) => c.extend(Some(#each_ident)),
-                                // If not, create an empty `Vec`, extend it using the provided value, and set it.
-                                None => {
-                                    let mut c = #container_ident::new();
-                                    c.extend(Some(#each_ident));
-                                    self.#field_ident = Some(c);
-                                }
-                            }
+                            #trace
+                            #extend
+                            #on_push
                             #b_ident {
-                                #(#req_moves,)*
+                                #(#before_req_moves,)*
+                                #extended_move,
+                                #(#after_req_moves,)*
                                 #(#opt_moves,)*
                                 #(#def_moves,)*
                             }
@@ -86,13 +566,73 @@ impl<'a> Generator<'a> {
                     }
                 );
 
-                // Rust doesn't support function overloading so we can't have two setter functions with the same name.
-                // Prefer the repeated setter over the other setter since the user was explicit about wanting a repeated setter.
-                if field_ident.clone().unwrap() != each {
-                    req_setters.push(req_setter);
+                let each_builder_setter = self.each_builder_setter(
+                    req_field,
+                    &each_ident,
+                    &item_type,
+                    quote! { self },
+                    quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* true, #(#after_pn,)* #(#st_type_pn,)*> },
+                )?;
+                req_setters.extend(each_builder_setter);
+            }
+
+            for alias_ident in self.alias_idents(req_field) {
+                req_setters.push(req_setter(&alias_ident, &arg_ty));
+            }
+
+            // The repeated setter takes precedence if it has the same name as the setter.
+            if let Some(setter_ident) = &setter_ident {
+                req_setters.push(req_setter(setter_ident, &arg_ty));
+
+                if let Some((borrowed_ident, borrowed_ty)) =
+                    self.borrowed_setter(req_field, setter_ident, field_ty)
+                {
+                    req_setters.push(req_setter(&borrowed_ident, &borrowed_ty));
                 }
-            } else {
-                req_setters.push(req_setter);
+            }
+
+            // The closure gets the builder of the field in its initial state, and returns it once it's complete.
+            if let Some((with_ident, init_path, complete_path)) = self.flatten_setter(req_field) {
+                req_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #with_ident<__B>(self, f: impl ::core::ops::FnOnce(#init_path) -> __B) -> #ret_ty
+                        where __B: #complete_path<Output = #field_ty>
+                    {
+                        let #field_ident = #complete_path::build(f(<#field_ty>::builder()));
+                        #trace
+                        #check
+                        #ret
+                    }
+                });
+            }
+
+            if let Some(extend_ident) = self.extend_ident(req_field) {
+                let item_type = collection_item(field_ty)?;
+                let container_ident = type_ident(field_ty)?;
+                let extend = self.req_extend(req_field, index, container_ident, quote! { iter });
+
+                req_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #extend_ident(mut self, iter: impl ::core::iter::IntoIterator<Item = #item_type>) ->
+                        #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* true, #(#after_pn,)* #(#st_type_pn,)*>
+                    {
+                        #trace
+                        #extend
+                        #b_ident {
+                            #(#before_req_moves,)*
+                            #extended_move,
+                            #(#after_req_moves,)*
+                            #(#opt_moves,)*
+                            #(#def_moves,)*
+                        }
+                    }
+                });
             }
         }
 
@@ -111,65 +651,267 @@ impl<'a> Generator<'a> {
                 continue;
             }
 
+            // The setters of the whole value of fields with `once` change the state, so they're generated by `once_setters`.
+            // Their repeated and extend setters don't, so they're generated here and can be called in any state.
+            let is_once = self.once_fields.contains(opt_field);
+
             let repeated_attr = self.f_attrs[opt_field].repeated();
+            let cfgs = self.f_attrs[opt_field].cfgs();
+            let deprecated = self.deprecated_attr(opt_field);
+            let vis = self.setter_vis(opt_field);
+            let trace = self.trace(opt_field);
+            let constness = self.constness(opt_field, inner_ty);
+            let setter_ident = self.value_setter_ident(opt_field);
+            let (arg_ty, arg_value) = self.setter_arg(opt_field, inner_ty)?;
+            let (check, arg_value) = self.check_value(opt_field, inner_ty, arg_value);
+            let (arg_ty, assign) = self.set_if_some(
+                opt_field,
+                arg_ty,
+                quote! {
+                    #check
+                    self.#field_ident = Some(#arg_value);
+                },
+            );
 
-            // Define these to be able to interpolate in quote.
-            let b_ident = &self.b_ident;
-            let b_const_pn = &self.b_const_pn;
-            let st_lifetime_pn = &self.st_lifetime_pn;
-            let st_const_pn = &self.st_const_pn;
-            let st_type_pn = &self.st_type_pn;
+            // These setters don't change the state, so they return `Self`.
+            let (ret_ty, ret) = self.checked_ret(opt_field, quote! { Self }, quote! { self });
 
             // No need to create a new state, so just set the value.
             // This setter is the non-repeated setter.
-            let opt_setter = quote! {
-                pub fn #field_ident(mut self, #field_ident: #inner_ty) ->
-                    #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
-                {
-                    self.#field_ident = Some(#field_ident);
-                    self
+            let opt_setter = |setter_ident: &syn::Ident, arg_ty: &proc_macro2::TokenStream| {
+                quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis #constness fn #setter_ident(mut self, #field_ident: #arg_ty) -> #ret_ty {
+                        #trace
+                        #assign
+                        #ret
+                    }
                 }
             };
 
             if let Some(each) = repeated_attr {
+                let item_type = collection_item(inner_ty)?;
                 let container_ident = type_ident(inner_ty)?;
-                let item_type = wrapped_in(inner_ty, Some("Vec"));
                 let each_ident = syn::Ident::new(each.as_str(), opt_field.span());
+                let on_push = self.on_push(
+                    opt_field,
+                    quote! { self.#field_ident.as_ref().and_then(|c| c.last()) },
+                );
+                let (each_ty, item) = self.each_arg(opt_field, &each_ident, &item_type);
 
                 // Repeated setter
                 // No need to create a new state, so just set the value.
                 opt_setters.push(quote! {
-                    pub fn #each_ident(mut self, #each_ident: #item_type) ->
-                        #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
-                    {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #each_ident(mut self, #each_ident: #each_ty) -> Self {
+                        #trace
+                        let item = #item;
                         match self.#field_ident.as_mut() {
                             // If the vector is already created, just extend it using the newly provided value.
-                            Some(c) => c.extend(Some(#each_ident)),
+                            Some(c) => c.extend(Some(item)),
                             // If not, create an empty `Vec`, extend it using the provided value, and set it.
                             None => {
                                 let mut c = #container_ident::new();
-                                c.extend(Some(#each_ident));
+                                c.extend(Some(item));
                                 self.#field_ident = Some(c);
                             }
                         }
+                        #on_push
 
                         self
                     }
                 });
 
-                // Rust doesn't support function overloading so we can't have two setter functions with the same name.
-                // Prefer the repeated setter over the other setter since the user was explicit about wanting a repeated setter.
-                if field_ident.clone().unwrap() != each {
-                    opt_setters.push(opt_setter);
-                }
+                let each_builder_setter = self.each_builder_setter(
+                    opt_field,
+                    &each_ident,
+                    &item_type,
+                    quote! { self },
+                    quote! { Self },
+                )?;
+                opt_setters.extend(each_builder_setter);
+            }
+
+            if let Some(replace_ident) = self.replace_ident(opt_field).filter(|_| !is_once) {
+                opt_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #replace_ident(mut self, #field_ident: #field_ty) -> Self {
+                        #trace
+                        self.#field_ident = #field_ident;
+                        self
+                    }
+                });
+            }
+
+            let alias_idents = if is_once {
+                vec![]
             } else {
-                opt_setters.push(opt_setter);
+                self.alias_idents(opt_field)
+            };
+            for alias_ident in alias_idents {
+                opt_setters.push(opt_setter(&alias_ident, &arg_ty));
+            }
+
+            // The repeated setter takes precedence if it has the same name as the setter.
+            if let Some(setter_ident) = setter_ident.as_ref().filter(|_| !is_once) {
+                opt_setters.push(opt_setter(setter_ident, &arg_ty));
+
+                if let Some((borrowed_ident, borrowed_ty)) =
+                    self.borrowed_setter(opt_field, setter_ident, inner_ty)
+                {
+                    opt_setters.push(opt_setter(&borrowed_ident, &borrowed_ty));
+                }
+            }
+
+            if let Some(extend_ident) = self.extend_ident(opt_field) {
+                let item_type = collection_item(inner_ty)?;
+                let container_ident = type_ident(inner_ty)?;
+
+                // No need to create a new state, so just extend the value.
+                opt_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #extend_ident(mut self, iter: impl ::core::iter::IntoIterator<Item = #item_type>) -> Self {
+                        #trace
+                        match self.#field_ident.as_mut() {
+                            // If the vector is already created, just extend it using the newly provided values.
+                            Some(c) => c.extend(iter),
+                            // If not, create an empty `Vec`, extend it using the provided values, and set it.
+                            None => {
+                                let mut c = #container_ident::new();
+                                c.extend(iter);
+                                self.#field_ident = Some(c);
+                            }
+                        }
+
+                        self
+                    }
+                });
             }
         }
 
         Ok(opt_setters)
     }
 
+    // Generate the setters of optional fields with `#[builder(once)]`.
+    //
+    // Each field gets an impl block of its own for the states in which its const generic parameter is `false`.
+    // The setters move to the state in which it's `true`, so they can't be called again.
+    pub fn once_setters(&self) -> Result<Vec<proc_macro2::TokenStream>, Error> {
+        let mut once_setters = vec![];
+
+        for (once_index, &once_field) in self.once_fields.iter().enumerate() {
+            let field_ident = &once_field.ident;
+            let inner_ty = is_option(&once_field.ty).unwrap();
+
+            let cfgs = self.f_attrs[once_field].cfgs();
+            let deprecated = self.deprecated_attr(once_field);
+            let vis = self.setter_vis(once_field);
+            let trace = self.trace(once_field);
+            let constness = self.constness(once_field, inner_ty);
+            let setter_ident = self.value_setter_ident(once_field);
+            let (arg_ty, arg_value) = self.setter_arg(once_field, inner_ty)?;
+            let (check, arg_value) = self.check_value(once_field, inner_ty, arg_value);
+
+            // The const generic parameters of optional fields come after the ones of the required fields.
+            let index = self.req_fields.len() + once_index;
+            let before_pn = &self.b_const_pn[..index];
+            let after_pn = &self.b_const_pn[index + 1..];
+            let before_p = &self.b_const_p[..index];
+            let after_p = &self.b_const_p[index + 1..];
+
+            // Move the other optional fields into the new state along with this one.
+            let opt_index = self
+                .opt_fields
+                .iter()
+                .position(|&opt_field| opt_field == once_field)
+                .unwrap();
+            let before_opt_moves = &self.opt_moves[..opt_index];
+            let after_opt_moves = &self.opt_moves[opt_index + 1..];
+
+            // Define these to be able to interpolate in quote.
+            let b_ident = &self.b_ident;
+            let where_clause = &self.where_clause;
+            let st_lifetime_pn = &self.st_lifetime_pn;
+            let st_const_pn = &self.st_const_pn;
+            let st_type_pn = &self.st_type_pn;
+            let st_lifetime_p = &self.st_lifetime_p;
+            let st_const_p = &self.st_const_p;
+            let st_type_p = &self.st_type_p;
+            let req_moves = &self.req_moves;
+            let def_moves = &self.def_moves;
+            let items_cfg = self.items_cfg();
+
+            let (ret_ty, ret) = self.checked_ret(
+                once_field,
+                quote! {
+                    #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* true, #(#after_pn,)* #(#st_type_pn,)*>
+                },
+                quote! {
+                    #b_ident {
+                        #(#req_moves,)*
+                        #(#before_opt_moves,)*
+                        #field_ident: Some(#arg_value),
+                        #(#after_opt_moves,)*
+                        #(#def_moves,)*
+                    }
+                },
+            );
+
+            let once_setter = |setter_ident: &syn::Ident, arg_ty: &proc_macro2::TokenStream| {
+                quote! {
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis #constness fn #setter_ident(self, #field_ident: #arg_ty) -> #ret_ty {
+                        #trace
+                        #check
+                        #ret
+                    }
+                }
+            };
+
+            // The repeated setter takes precedence if it has the same name as the setter.
+            let mut setters = vec![];
+            if let Some(setter_ident) = &setter_ident {
+                setters.push(once_setter(setter_ident, &arg_ty));
+
+                if let Some((borrowed_ident, borrowed_ty)) =
+                    self.borrowed_setter(once_field, setter_ident, inner_ty)
+                {
+                    setters.push(once_setter(&borrowed_ident, &borrowed_ty));
+                }
+            }
+            for alias_ident in self.alias_idents(once_field) {
+                setters.push(once_setter(&alias_ident, &arg_ty));
+            }
+
+            once_setters.push(quote! {
+                #items_cfg
+                #(#cfgs)*
+                impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#before_p,)* #(#after_p,)* #(#st_type_p,)*>
+                    #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#before_pn,)* false, #(#after_pn,)* #(#st_type_pn,)*>
+                    #where_clause
+                {
+                    #(#setters)*
+                }
+            });
+        }
+
+        Ok(once_setters)
+    }
+
     pub fn def_setters(&self) -> Result<Vec<proc_macro2::TokenStream>, Error> {
         let mut def_setters = vec![];
 
@@ -177,55 +919,142 @@ impl<'a> Generator<'a> {
             let field_ident = &def_field.ident;
             let field_ty = &def_field.ty;
 
-            if self.f_attrs[def_field].should_skip() {
+            if self.f_attrs[def_field].should_skip() && !self.seeded_by_value(def_field) {
                 continue;
             }
 
             let repeated_attr = self.f_attrs[def_field].repeated();
+            let cfgs = self.f_attrs[def_field].cfgs();
+            let deprecated = self.deprecated_attr(def_field);
+            let vis = self.setter_vis(def_field);
+            let trace = self.trace(def_field);
+            let constness = self.constness(def_field, field_ty);
+            // The value of a field seeded by its `value` can't be replaced.
+            let setter_ident = self
+                .value_setter_ident(def_field)
+                .filter(|_| !self.seeded_by_value(def_field));
+            let (arg_ty, arg_value) = self.setter_arg(def_field, field_ty)?;
+            let (check, mut arg_value) = self.check_value(def_field, field_ty, arg_value);
 
-            // Define these to be able to interpolate in quote.
-            let b_ident = &self.b_ident;
-            let b_const_pn = &self.b_const_pn;
-            let st_lifetime_pn = &self.st_lifetime_pn;
-            let st_const_pn = &self.st_const_pn;
-            let st_type_pn = &self.st_type_pn;
+            // Fields with `default_from` are stored in an `Option` until they're built.
+            if self.f_attrs[def_field].default_from().is_some() {
+                arg_value = quote! { Some(#arg_value) };
+            }
+
+            let (arg_ty, assign) = self.set_if_some(
+                def_field,
+                arg_ty,
+                quote! {
+                    #check
+                    self.#field_ident = #arg_value;
+                },
+            );
+
+            // These setters don't change the state, so they return `Self`.
+            let (ret_ty, ret) = self.checked_ret(def_field, quote! { Self }, quote! { self });
 
             // No need to create a new state, so just set the value.
-            let def_setter = quote! {
-                pub fn #field_ident(mut self, #field_ident: #field_ty) ->
-                    #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
-                {
-                    self.#field_ident = #field_ident;
-                    self
+            let def_setter = |setter_ident: &syn::Ident, arg_ty: &proc_macro2::TokenStream| {
+                quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis #constness fn #setter_ident(mut self, #field_ident: #arg_ty) -> #ret_ty {
+                        #trace
+                        #assign
+                        #ret
+                    }
                 }
             };
 
             if let Some(each) = repeated_attr {
-                let item_type = wrapped_in(field_ty, Some("Vec"));
+                let item_type = collection_item(field_ty)?;
                 let each_ident = syn::Ident::new(each.as_str(), field_ty.span());
+                let on_push = self.on_push(def_field, quote! { self.#field_ident.last() });
+                let (each_ty, item) = self.each_arg(def_field, &each_ident, &item_type);
 
                 // Repeated setter
                 // No need to create a new state, so just set the value.
                 def_setters.push(quote! {
-                    pub fn #each_ident(mut self, #each_ident: #item_type) ->
-                        #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
-                    {
-                        self.#field_ident.extend(Some(#each_ident));
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #each_ident(mut self, #each_ident: #each_ty) -> Self {
+                        #trace
+                        self.#field_ident.extend(Some(#item));
+                        #on_push
 
                         self
                     }
                 });
 
-                // Rust doesn't support function overloading so we can't have two setter functions with the same name.
-                // Prefer the repeated setter over the other setter since the user was explicit about wanting a repeated setter.
-                if field_ident.clone().unwrap() != each {
-                    def_setters.push(def_setter);
+                let each_builder_setter = self.each_builder_setter(
+                    def_field,
+                    &each_ident,
+                    &item_type,
+                    quote! { self },
+                    quote! { Self },
+                )?;
+                def_setters.extend(each_builder_setter);
+            }
+
+            if !self.seeded_by_value(def_field) {
+                for alias_ident in self.alias_idents(def_field) {
+                    def_setters.push(def_setter(&alias_ident, &arg_ty));
+                }
+            }
+
+            // The repeated setter takes precedence if it has the same name as the setter.
+            if let Some(setter_ident) = &setter_ident {
+                def_setters.push(def_setter(setter_ident, &arg_ty));
+
+                if let Some((borrowed_ident, borrowed_ty)) =
+                    self.borrowed_setter(def_field, setter_ident, field_ty)
+                {
+                    def_setters.push(def_setter(&borrowed_ident, &borrowed_ty));
                 }
-            } else {
-                def_setters.push(def_setter);
+            }
+
+            if let Some(extend_ident) = self.extend_ident(def_field) {
+                let item_type = collection_item(field_ty)?;
+
+                // No need to create a new state, so just extend the value.
+                def_setters.push(quote! {
+                    #(#cfgs)*
+                    #deprecated
+                    #[must_use]
+                    #[inline]
+                    #vis fn #extend_ident(mut self, iter: impl ::core::iter::IntoIterator<Item = #item_type>) -> Self {
+                        #trace
+                        self.#field_ident.extend(iter);
+
+                        self
+                    }
+                });
             }
         }
 
         Ok(def_setters)
     }
 }
+
+// Returns the `{Builder}Init` alias and the `{Builder}Complete` trait of the builder of `ty`, which derives `Builder`.
+// They're named after the last segment of the path of the type.
+fn builder_paths(ty: &syn::Type) -> Option<(syn::Path, syn::Path)> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let mut init_path = path.clone();
+    let init_segment = init_path.segments.last_mut()?;
+    init_segment.ident = format_ident!("{}BuilderInit", init_segment.ident);
+
+    let mut complete_path = path.clone();
+    let complete_segment = complete_path.segments.last_mut()?;
+    complete_segment.ident = format_ident!("{}BuilderComplete", complete_segment.ident);
+    complete_segment.arguments = syn::PathArguments::None;
+
+    Some((init_path, complete_path))
+}
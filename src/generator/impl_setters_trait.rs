@@ -0,0 +1,107 @@
+use quote::{format_ident, quote};
+
+use super::Generator;
+
+impl<'a> Generator<'a> {
+    // Generate the `{Struct}Setters` trait if requested by `#[builder(setters_trait)]`, and implement it for every state of the builder.
+    //
+    // It declares the `setters` of optional and default fields, which return `Self` since they don't change the state.
+    // So generic code can call them on any state of the builder through a `{Struct}Setters` bound.
+    // The setters of required fields change the type of the builder, so they're not part of the trait.
+    pub fn setters_trait<'s>(
+        &self,
+        setters: impl IntoIterator<Item = &'s proc_macro2::TokenStream>,
+    ) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.setters_trait() {
+            return None;
+        }
+
+        let mut trait_items = vec![];
+        let mut impl_items = vec![];
+        for setter in setters {
+            // The setters are generated as methods, so they always parse.
+            let method: syn::ImplItemMethod = syn::parse2(setter.clone()).unwrap();
+
+            // Trait methods are as visible as the trait, so the setters restricted by `#[builder(vis = "..")]` are left out.
+            if !matches!(method.vis, syn::Visibility::Public(_)) {
+                continue;
+            }
+
+            let method_ident = &method.sig.ident;
+
+            // Patterns like `mut self` are not allowed in methods without a body, and trait methods can't be `const`.
+            let mut sig = method.sig.clone();
+            sig.constness = None;
+            let mut arg_idents = vec![];
+            for arg in sig.inputs.iter_mut() {
+                match arg {
+                    syn::FnArg::Receiver(receiver) => receiver.mutability = None,
+                    syn::FnArg::Typed(typed) => {
+                        if let syn::Pat::Ident(pat) = typed.pat.as_mut() {
+                            pat.mutability = None;
+                            arg_idents.push(pat.ident.clone());
+                        }
+                    }
+                }
+            }
+
+            let attrs: Vec<_> = method
+                .attrs
+                .iter()
+                .filter(|attr| {
+                    ["cfg", "deprecated", "must_use"]
+                        .iter()
+                        .any(|name| attr.path.is_ident(name))
+                })
+                .collect();
+            let cfgs = attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+
+            trait_items.push(quote! {
+                #(#attrs)*
+                #sig;
+            });
+
+            // Inherent methods take precedence over the ones of the trait, so this calls the setter.
+            impl_items.push(quote! {
+                #(#cfgs)*
+                #[inline]
+                #[allow(deprecated)]
+                #sig {
+                    Self::#method_ident(self, #(#arg_idents),*)
+                }
+            });
+        }
+
+        // Define these to be able to interpolate in quote.
+        let trait_ident = format_ident!("{}Setters", self.s_ident);
+        let s_vis = self.s_vis;
+        let b_ident = &self.b_ident;
+        let where_clause = &self.where_clause;
+        let b_const_p = &self.b_const_p;
+        let b_const_pn = &self.b_const_pn;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let items_cfg = self.items_cfg();
+
+        // The setters take and return the builder by value, so the trait requires `Sized`.
+        Some(quote! {
+            #items_cfg
+            #s_vis trait #trait_ident<#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*>: ::core::marker::Sized #where_clause {
+                #(#trait_items)*
+            }
+
+            #items_cfg
+            impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)*>
+                #trait_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*> for
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
+                #where_clause
+            {
+                #(#impl_items)*
+            }
+        })
+    }
+}
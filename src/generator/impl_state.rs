@@ -0,0 +1,121 @@
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+
+use super::Generator;
+use crate::err::Error;
+
+// The most fields that the state enum tracks, since it has a variant for each of their combinations.
+const MAX_STATE_FIELDS: usize = 6;
+
+impl<'a> Generator<'a> {
+    // Returns the name of the enum of `#[builder(state_enum)]`.
+    fn state_enum_ident(&self) -> syn::Ident {
+        format_ident!("{}State", self.b_ident)
+    }
+
+    // Generate the enum of `#[builder(state_enum)]` which has a variant wrapping the builder in each of its states,
+    // along with the conversions of the builder into it.
+    //
+    // The state is tracked by the required fields and the optional fields with `once`, so the variants are named
+    // after the ones that are set, e.g. `WithHostAndPort`. The variant of the initial state is named `Init`.
+    // The builder of `mode = runtime` has a single state, so it gets no enum.
+    pub fn state_enum(&self) -> Result<Option<proc_macro2::TokenStream>, Error> {
+        if !self.s_attrs.state_enum() || self.s_attrs.runtime_mode() {
+            return Ok(None);
+        }
+
+        let state_fields: Vec<_> = self.req_fields.iter().chain(&self.once_fields).collect();
+        if state_fields.len() > MAX_STATE_FIELDS {
+            return Err(Error::StateEnumTooLarge(self.s_ident.clone()));
+        }
+
+        // Define these to be able to interpolate in quote.
+        let enum_ident = self.state_enum_ident();
+        let b_ident = &self.b_ident;
+        let s_vis = self.s_vis;
+        let where_clause = &self.where_clause;
+        let st_lifetime_p = &self.st_lifetime_p;
+        let st_const_p = &self.st_const_p;
+        let st_type_p = &self.st_type_p;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let items_cfg = self.items_cfg();
+
+        let mut variants = vec![];
+        let mut conversions = vec![];
+        for state in 0..1usize << state_fields.len() {
+            let is_set = |index: usize| state & (1 << index) != 0;
+
+            let set_names: Vec<_> = state_fields
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| is_set(*index))
+                .map(|(_, field)| {
+                    let name = field.ident.as_ref().unwrap().to_string();
+
+                    name.to_case(Case::Pascal)
+                })
+                .collect();
+            let variant = if set_names.is_empty() {
+                format_ident!("Init")
+            } else {
+                format_ident!("With{}", set_names.join("And"))
+            };
+
+            let b_const_pn = (0..state_fields.len()).map(|index| {
+                let is_set = is_set(index);
+
+                quote! { #is_set }
+            });
+            let builder_ty = quote! {
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*>
+            };
+
+            variants.push(quote! { #variant(#builder_ty) });
+            conversions.push(quote! {
+                #items_cfg
+                impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*> ::core::convert::From<#builder_ty>
+                    for #enum_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*>
+                    #where_clause
+                {
+                    fn from(builder: #builder_ty) -> Self {
+                        Self::#variant(builder)
+                    }
+                }
+            });
+        }
+
+        Ok(Some(quote! {
+            #items_cfg
+            #s_vis enum #enum_ident<#(#st_lifetime_p,)* #(#st_const_p,)* #(#st_type_p,)*> #where_clause {
+                #(#variants),*
+            }
+
+            #(#conversions)*
+        }))
+    }
+
+    // Returns `into_state` which erases the state of the builder by wrapping it in the enum of `#[builder(state_enum)]`.
+    pub fn erase_state_fn(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.state_enum() {
+            return None;
+        }
+
+        let enum_ident = self.state_enum_ident();
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let enum_ty =
+            quote! { #enum_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#st_type_pn,)*> };
+
+        Some(quote! {
+            #[must_use]
+            pub fn into_state(self) -> #enum_ty
+                where #enum_ty: ::core::convert::From<Self>
+            {
+                ::core::convert::From::from(self)
+            }
+        })
+    }
+}
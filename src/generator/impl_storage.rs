@@ -0,0 +1,264 @@
+use quote::{format_ident, quote};
+
+use super::Generator;
+
+impl<'a> Generator<'a> {
+    // Returns the name of the type storing the required fields with `#[builder(storage = maybe_uninit)]`.
+    fn slot_ident(&self) -> syn::Ident {
+        format_ident!("__{}Slot", self.b_ident)
+    }
+
+    // Returns the type of the builder field storing the required field of type `ty`.
+    // `ct_param` is the const generic parameter tracking whether the field is set.
+    pub fn req_storage_ty(
+        &self,
+        ty: &syn::Type,
+        ct_param: &syn::Ident,
+    ) -> proc_macro2::TokenStream {
+        if self.s_attrs.maybe_uninit() {
+            let slot_ident = self.slot_ident();
+
+            quote! { #slot_ident<#ty, #ct_param> }
+        } else {
+            quote! { ::core::option::Option<#ty> }
+        }
+    }
+
+    // Returns the value of a required field that is not set yet.
+    pub fn req_empty(&self) -> proc_macro2::TokenStream {
+        if self.s_attrs.maybe_uninit() {
+            let slot_ident = self.slot_ident();
+
+            quote! { #slot_ident::uninit() }
+        } else {
+            quote! { None }
+        }
+    }
+
+    // Returns the value of a required field that is set to `value`.
+    pub fn req_store(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.s_attrs.maybe_uninit() {
+            let slot_ident = self.slot_ident();
+
+            quote! { #slot_ident::new(#value) }
+        } else {
+            quote! { Some(#value) }
+        }
+    }
+
+    // Returns the expression moving the value out of the required field `field` of the builder.
+    // It must be used in an `unsafe` block and only if the field is set.
+    pub fn req_take(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+
+        if self.s_attrs.maybe_uninit() {
+            quote! { self.#field_ident.assume_init() }
+        } else {
+            quote! { self.#field_ident.unwrap_unchecked() }
+        }
+    }
+
+    // Returns the expression cloning the value of the required field `field` of the builder.
+    // It must be used in an `unsafe` block and only if the field is set.
+    pub fn req_clone(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+
+        if self.s_attrs.maybe_uninit() {
+            quote! { ::core::clone::Clone::clone(self.#field_ident.assume_init_ref()) }
+        } else {
+            quote! { ::core::clone::Clone::clone(self.#field_ident.as_ref().unwrap_unchecked()) }
+        }
+    }
+
+    // Returns the expression moving the value out of the required field `field` of the builder,
+    // or returning its default value if the field is not set.
+    pub fn req_take_or_default(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+        let field_ty = &field.ty;
+
+        if self.s_attrs.maybe_uninit() {
+            let index = self
+                .req_fields
+                .iter()
+                .position(|&req_field| req_field == field)
+                .unwrap();
+            let ct_param = &self.b_const_pn[index];
+
+            quote! {
+                if #ct_param {
+                    unsafe { self.#field_ident.assume_init() }
+                } else {
+                    <#field_ty as ::core::default::Default>::default()
+                }
+            }
+        } else {
+            quote! {
+                match self.#field_ident {
+                    Some(value) => value,
+                    None => <#field_ty as ::core::default::Default>::default(),
+                }
+            }
+        }
+    }
+
+    // Returns the expression borrowing the value of the required field `field` of the builder as an `Option`.
+    pub fn req_ref(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+
+        if self.s_attrs.maybe_uninit() {
+            let index = self
+                .req_fields
+                .iter()
+                .position(|&req_field| req_field == field)
+                .unwrap();
+            let ct_param = &self.b_const_pn[index];
+
+            quote! {
+                if #ct_param {
+                    Some(unsafe { self.#field_ident.assume_init_ref() })
+                } else {
+                    None
+                }
+            }
+        } else {
+            quote! { self.#field_ident.as_ref() }
+        }
+    }
+
+    // Returns the statement that extends the collection of the required field `field` using `items`,
+    // creating the collection first if the field is not set yet. `index` is the index of the field.
+    pub fn req_extend(
+        &self,
+        field: &syn::Field,
+        index: usize,
+        container_ident: &syn::Ident,
+        items: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+
+        if self.s_attrs.maybe_uninit() {
+            let ct_param = &self.b_const_pn[index];
+            let slot_ident = self.slot_ident();
+
+            quote! {
+                if #ct_param {
+                    unsafe { self.#field_ident.assume_init_mut() }.extend(#items);
+                } else {
+                    let mut c = #container_ident::new();
+                    c.extend(#items);
+                    self.#field_ident = #slot_ident::new(c);
+                }
+            }
+        } else {
+            quote! {
+                match self.#field_ident.as_mut() {
+                    // If the vector is already created, just extend it using the newly provided values.
+                    Some(c) => c.extend(#items),
+                    // If not, create an empty `Vec`, extend it using the provided values, and set it.
+                    None => {
+                        let mut c = #container_ident::new();
+                        c.extend(#items);
+                        self.#field_ident = Some(c);
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns the expression returning a reference to the last item of the collection of the required field `field`.
+    // It must only be used after `req_extend`.
+    pub fn req_last(&self, field: &syn::Field) -> proc_macro2::TokenStream {
+        let field_ident = &field.ident;
+
+        if self.s_attrs.maybe_uninit() {
+            quote! { unsafe { self.#field_ident.assume_init_ref() }.last() }
+        } else {
+            quote! { self.#field_ident.as_ref().and_then(|c| c.last()) }
+        }
+    }
+
+    // Returns the code to move the required field `field` into the next state after `req_extend` sets it.
+    pub fn req_extended_move(&self, field: &syn::Field, index: usize) -> proc_macro2::TokenStream {
+        if self.s_attrs.maybe_uninit() {
+            let field_ident = &field.ident;
+            let cfgs = self.f_attrs[field].cfgs();
+
+            quote! { #(#cfgs)* #field_ident: unsafe { self.#field_ident.assume_set() } }
+        } else {
+            self.req_moves[index].clone()
+        }
+    }
+
+    // Generate the type storing the required fields if requested by `#[builder(storage = maybe_uninit)]`.
+    //
+    // The type carries the const generic parameter tracking whether its field is set.
+    // So it knows whether to drop its value, and builders that are dropped before being built don't leak.
+    pub fn slot_type(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.s_attrs.maybe_uninit() {
+            return None;
+        }
+
+        let slot_ident = self.slot_ident();
+        let items_cfg = self.items_cfg();
+
+        Some(quote! {
+            #items_cfg
+            #[doc(hidden)]
+            struct #slot_ident<T, const SET: bool>(::core::mem::MaybeUninit<T>);
+
+            #items_cfg
+            #[allow(dead_code)]
+            impl<T, const SET: bool> #slot_ident<T, SET> {
+                const fn uninit() -> Self {
+                    #slot_ident(::core::mem::MaybeUninit::uninit())
+                }
+
+                const fn new(value: T) -> Self {
+                    #slot_ident(::core::mem::MaybeUninit::new(value))
+                }
+
+                // Safety: the value must be set.
+                unsafe fn assume_init(self) -> T {
+                    let this = ::core::mem::ManuallyDrop::new(self);
+
+                    this.0.assume_init_read()
+                }
+
+                // Safety: the value must be set.
+                unsafe fn assume_init_ref(&self) -> &T {
+                    self.0.assume_init_ref()
+                }
+
+                // Safety: the value must be set.
+                unsafe fn assume_init_mut(&mut self) -> &mut T {
+                    self.0.assume_init_mut()
+                }
+
+                // Safety: the value must be set.
+                unsafe fn assume_set(self) -> #slot_ident<T, true> {
+                    #slot_ident::new(self.assume_init())
+                }
+            }
+
+            #items_cfg
+            impl<T, const SET: bool> ::core::ops::Drop for #slot_ident<T, SET> {
+                fn drop(&mut self) {
+                    if SET {
+                        unsafe { self.0.assume_init_drop() }
+                    }
+                }
+            }
+
+            #items_cfg
+            impl<T: ::core::clone::Clone, const SET: bool> ::core::clone::Clone for #slot_ident<T, SET> {
+                fn clone(&self) -> Self {
+                    if SET {
+                        #slot_ident::new(unsafe { self.assume_init_ref() }.clone())
+                    } else {
+                        #slot_ident::uninit()
+                    }
+                }
+            }
+        })
+    }
+}
@@ -1,27 +1,48 @@
+mod impl_check;
+mod impl_clone;
 mod impl_constraint;
+mod impl_debug;
 mod impl_default;
+mod impl_extend;
 mod impl_init;
+mod impl_mut;
+mod impl_pairs;
+mod impl_partial;
 mod impl_setter;
+mod impl_setters_trait;
+mod impl_state;
+mod impl_storage;
 
 use std::collections::HashMap;
 
 use quote::{format_ident, quote};
 
-use crate::attribute::{parse_attrs, FieldAttrs};
+use crate::attribute::{parse_attrs, parse_struct_attrs, FieldAttrs, StructAttrs};
 use crate::err::Error;
-use crate::generics::{param_to_name, split_param_names, split_params, GenericParamName};
-use crate::wrap::is_option;
+use crate::generics::{
+    param_to_name, split_param_names, split_params, trivial_bound, GenericParamName,
+};
+use crate::wrap::{array_len, is_option, is_phantom_data, is_vec};
 
 pub struct Generator<'a> {
     // Map from a field to its parsed attributes
     f_attrs: HashMap<&'a syn::Field, FieldAttrs>,
 
+    // Parsed attributes of the struct
+    s_attrs: StructAttrs,
+
     // Builder name
     b_ident: syn::Ident,
 
     // Struct name
     s_ident: syn::Ident,
 
+    // Struct visibility
+    s_vis: &'a syn::Visibility,
+
+    // Fields of the struct in the order of their declaration
+    s_fields: Vec<&'a syn::Field>,
+
     // Different pieces of a type’s generics required for impl’ing a trait for that type.
     //
     // impl<const N: usize, T> Foo<N, T> where T: std::fmt::Display
@@ -79,6 +100,39 @@ pub struct Generator<'a> {
     opt_fields: Vec<&'a syn::Field>,
     def_fields: Vec<&'a syn::Field>,
 
+    // Array fields with `#[builder(per_index)]` mapped to the fields representing their elements.
+    //
+    // struct Foo {
+    //    #[builder(per_index)]
+    //    coords: [f64; 2]
+    // }
+    //
+    // Each element is treated as a required field of its own, like `coords_0: f64` and `coords_1: f64`.
+    // These fields are only part of the builder and are put back together into an array when building the struct.
+    array_slots: Vec<(&'a syn::Field, Vec<&'a syn::Field>)>,
+
+    // `Option` fields with `#[builder(required)]` mapped to the fields representing their inner value.
+    //
+    // struct Foo {
+    //    #[builder(required)]
+    //    bar: Option<usize>
+    // }
+    //
+    // The inner value is treated as a required field of its own, like `bar: usize`.
+    // It's wrapped in `Some` when building the struct.
+    required_options: Vec<(&'a syn::Field, &'a syn::Field)>,
+
+    // Optional fields with `#[builder(once)]`, which are also part of `opt_fields`.
+    //
+    // struct Foo {
+    //    #[builder(once)]
+    //    bar: Option<usize>
+    // }
+    //
+    // Each has a const generic parameter which is set to `true` by its setter, so the setter can't be called again.
+    // Unlike the ones of the required fields, these parameters don't guard the `build` function.
+    once_fields: Vec<&'a syn::Field>,
+
     // All builder const generics set to false.
     // Represents the initial state of the state machine.
     all_false: Vec<proc_macro2::TokenStream>,
@@ -106,6 +160,13 @@ pub struct Generator<'a> {
     // we will call `unwrap` on the required fields because we know they are not `None`.
     // This variable contains the code to unwrap the required fields.
     req_unwraps: Vec<proc_macro2::TokenStream>,
+
+    // Default fields computed from the built struct via `#[builder(default_from = ...)]` start as `Default::default()`
+    // in the built struct. Then they are either set to the provided value, or computed in the order of their declaration.
+    // def_builds:   Contains the code to move the default fields into the built struct
+    // def_computes: Contains the code to compute the fields with `default_from`
+    def_builds: Vec<proc_macro2::TokenStream>,
+    def_computes: Vec<proc_macro2::TokenStream>,
 }
 
 impl<'a> Generator<'a> {
@@ -115,11 +176,31 @@ impl<'a> Generator<'a> {
                 syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
                     let fields = named;
                     let s_ident = ast.ident.clone();
+                    let s_attrs = parse_struct_attrs(ast)?;
+
+                    // The borrowing builder doesn't track which fields are set in its type,
+                    // so it can't know which ones to drop.
+                    if s_attrs.maybe_uninit() && s_attrs.both_modes() {
+                        return Err(Error::MaybeUninitBothModes(s_ident));
+                    }
+
+                    if s_attrs.maybe_uninit() && s_attrs.runtime_mode() {
+                        return Err(Error::MaybeUninitRuntime(s_ident));
+                    }
+
+                    // `from_pairs` stores the values in the builder as is, and checks the required fields at runtime.
+                    if s_attrs.maybe_uninit() && s_attrs.builds_from_pairs() {
+                        return Err(Error::MaybeUninitFromPairs(s_ident));
+                    }
 
                     // Map each field to its parsed attributes.
                     let mut f_attrs = HashMap::with_capacity(fields.len());
                     for field in fields {
-                        let attrs = parse_attrs(field)?;
+                        let mut attrs = parse_attrs(field)?;
+
+                        if is_phantom_data(&field.ty) {
+                            attrs.phantom_data();
+                        }
 
                         f_attrs.insert(field, attrs);
                     }
@@ -147,14 +228,201 @@ impl<'a> Generator<'a> {
                     let mut req_fields = vec![];
                     let mut opt_fields = vec![];
                     let mut def_fields = vec![];
+                    let mut array_slots = vec![];
+                    let mut required_options = vec![];
+                    let mut once_fields = vec![];
                     for field in fields {
                         let is_default = f_attrs[field].is_default().is_some();
-                        let is_option = is_option(&field.ty).is_some();
+                        let is_default_from = f_attrs[field].default_from().is_some();
+                        let is_try_default = f_attrs[field].try_default().is_some();
+                        let inner_ty = is_option(&field.ty);
+                        let is_option = inner_ty.is_some();
+
+                        if is_default_from
+                            && (is_default
+                                || f_attrs[field].once_cell()
+                                || f_attrs[field].repeated().is_some()
+                                || f_attrs[field].extend().is_some())
+                        {
+                            return Err(Error::DefaultFromConflict(field.clone()));
+                        }
+
+                        // The hook gets the last item of the collection, which only `Vec` provides.
+                        if f_attrs[field].on_push().is_some()
+                            && !is_vec(inner_ty.unwrap_or(&field.ty))
+                        {
+                            return Err(Error::OnPushNotVec(field.ty.clone()));
+                        }
+
+                        if is_try_default
+                            && (is_default || is_default_from || f_attrs[field].per_index())
+                        {
+                            return Err(Error::TryDefaultConflict(field.clone()));
+                        }
+
+                        // The struct can't implement `Default` if creating the builder is fallible.
+                        if is_try_default && s_attrs.default() {
+                            return Err(Error::DefaultTryDefault(field.clone()));
+                        }
+
+                        // The default error type boxes a `std::error::Error`.
+                        if is_try_default && s_attrs.no_std() && s_attrs.error().is_none() {
+                            return Err(Error::TryDefaultNoStd(field.clone()));
+                        }
+
+                        if f_attrs[field].required()
+                            && (!is_option
+                                || is_default
+                                || is_default_from
+                                || is_try_default
+                                || f_attrs[field].should_skip())
+                        {
+                            return Err(Error::RequiredConflict(field.clone()));
+                        }
+
+                        if f_attrs[field].required() && s_attrs.converts_into_builder() {
+                            return Err(Error::RequiredIntoBuilder(field.clone()));
+                        }
+
+                        if f_attrs[field].check_error().is_some() && !f_attrs[field].checked() {
+                            return Err(Error::CheckErrorWithoutCheck(field.clone()));
+                        }
+
+                        // The check runs in the setter of the whole value.
+                        if f_attrs[field].check().is_some()
+                            && (f_attrs[field].repeated().is_some()
+                                || f_attrs[field].extend().is_some()
+                                || f_attrs[field].per_index()
+                                || f_attrs[field].should_skip())
+                        {
+                            return Err(Error::CheckConflict(field.clone()));
+                        }
+
+                        if f_attrs[field].check_with().is_some()
+                            && (f_attrs[field].check().is_some()
+                                || f_attrs[field].repeated().is_some()
+                                || f_attrs[field].extend().is_some()
+                                || f_attrs[field].per_index()
+                                || f_attrs[field].should_skip())
+                        {
+                            return Err(Error::CheckWithConflict(field.clone()));
+                        }
+
+                        if f_attrs[field].once()
+                            && (!is_option
+                                || f_attrs[field].required()
+                                || f_attrs[field].set_if_some()
+                                || f_attrs[field].should_skip())
+                        {
+                            return Err(Error::OnceConflict(field.clone()));
+                        }
+
+                        // The builder of the field is named after the last segment of the path of its type.
+                        let is_path = matches!(
+                            &field.ty,
+                            syn::Type::Path(syn::TypePath { qself: None, .. })
+                        );
+                        if f_attrs[field].flatten()
+                            && (!is_path
+                                || is_option
+                                || is_default
+                                || is_default_from
+                                || is_try_default
+                                || f_attrs[field].repeated().is_some()
+                                || f_attrs[field].extend().is_some()
+                                || f_attrs[field].wrap()
+                                || f_attrs[field].to_string()
+                                || f_attrs[field].should_skip())
+                        {
+                            return Err(Error::FlattenConflict(field.clone()));
+                        }
+
+                        if f_attrs[field].each_builder() && f_attrs[field].repeated().is_none() {
+                            return Err(Error::EachBuilderConflict(field.clone()));
+                        }
+
+                        // The values set by `from_pairs` don't go through the setters, so they can't be checked.
+                        // The builder it sets can't be created if a default value fails.
+                        if s_attrs.builds_from_pairs()
+                            && (f_attrs[field].checked() || is_try_default)
+                        {
+                            return Err(Error::FromPairsConflict(field.clone()));
+                        }
+
+                        if f_attrs[field].once() && (s_attrs.both_modes() || s_attrs.runtime_mode())
+                        {
+                            return Err(Error::OnceUntracked(field.clone()));
+                        }
+
+                        if f_attrs[field].once_cell() && !is_default {
+                            return Err(Error::OnceCellWithoutDefault(field.clone()));
+                        }
+
+                        // `OnceLock` lives in `std`.
+                        if f_attrs[field].once_cell() && s_attrs.no_std() {
+                            return Err(Error::OnceCellNoStd(field.clone()));
+                        }
+
+                        if f_attrs[field].per_index()
+                            && (is_option || is_default || is_default_from)
+                        {
+                            return Err(Error::PerIndexNotArray(field.clone()));
+                        }
+
+                        if f_attrs[field].required() {
+                            let unwrapped = syn::Field {
+                                ty: inner_ty.unwrap().clone(),
+                                ..field.clone()
+                            };
+
+                            // Leaked like the element fields of arrays with `per_index`.
+                            let unwrapped: &'a syn::Field = Box::leak(Box::new(unwrapped));
+
+                            f_attrs.insert(unwrapped, parse_attrs(unwrapped)?);
+                            req_fields.push(unwrapped);
+                            required_options.push((field, unwrapped));
+                        } else if is_option {
+                            if f_attrs[field].once() {
+                                once_fields.push(field);
+                            }
 
-                        if is_option {
                             opt_fields.push(field);
-                        } else if is_default {
+                        } else if is_default || is_default_from || is_try_default {
                             def_fields.push(field);
+                        } else if f_attrs[field].per_index() {
+                            let (elem_ty, len) = array_len(&field.ty)
+                                .ok_or_else(|| Error::PerIndexNotArray(field.clone()))?;
+
+                            let mut slots = vec![];
+                            for index in 0..len {
+                                let slot = syn::Field {
+                                    // The elements are compiled in only if the array is.
+                                    attrs: field
+                                        .attrs
+                                        .iter()
+                                        .filter(|attr| attr.path.is_ident("cfg"))
+                                        .cloned()
+                                        .collect(),
+                                    vis: field.vis.clone(),
+                                    ident: Some(format_ident!(
+                                        "{}_{}",
+                                        field.ident.as_ref().unwrap(),
+                                        index
+                                    )),
+                                    colon_token: field.colon_token,
+                                    ty: elem_ty.clone(),
+                                };
+
+                                // The generator borrows the fields from the input of the derive. The element fields are
+                                // leaked to live as long since each derive runs once and they're tiny.
+                                let slot: &'a syn::Field = Box::leak(Box::new(slot));
+
+                                f_attrs.insert(slot, parse_attrs(slot)?);
+                                req_fields.push(slot);
+                                slots.push(slot);
+                            }
+
+                            array_slots.push((field, slots));
                         } else {
                             req_fields.push(field);
                         }
@@ -162,8 +430,11 @@ impl<'a> Generator<'a> {
 
                     let mut generator = Generator {
                         f_attrs,
+                        s_attrs,
                         b_ident,
                         s_ident,
+                        s_vis: &ast.vis,
+                        s_fields: fields.iter().collect(),
 
                         impl_generics,
                         ty_generics,
@@ -179,6 +450,9 @@ impl<'a> Generator<'a> {
                         req_fields,
                         opt_fields,
                         def_fields,
+                        array_slots,
+                        required_options,
+                        once_fields,
 
                         all_false: vec![],
 
@@ -192,6 +466,9 @@ impl<'a> Generator<'a> {
                         def_moves: vec![],
 
                         req_unwraps: vec![],
+
+                        def_builds: vec![],
+                        def_computes: vec![],
                     };
 
                     generator.req_init();
@@ -208,18 +485,249 @@ impl<'a> Generator<'a> {
         }
     }
 
+    // Returns the crate that provides allocated types like `Vec`.
+    // For `no_std` structs, it's the `alloc` crate which must be declared using `extern crate alloc`.
+    fn alloc(&self) -> proc_macro2::TokenStream {
+        if self.s_attrs.no_std() {
+            quote! { ::alloc }
+        } else {
+            quote! { ::std }
+        }
+    }
+
+    // Returns the `cfg` attributes that every generated item must carry.
+    // With `#[builder(test_only)]`, the builder only exists in tests.
+    // With `#[builder(feature = "name")]`, the builder only exists when the feature of the crate is enabled.
+    fn items_cfg(&self) -> proc_macro2::TokenStream {
+        let test_only = self.s_attrs.test_only().then(|| quote! { #[cfg(test)] });
+        let feature = self
+            .s_attrs
+            .feature()
+            .map(|feature| quote! { #[cfg(feature = #feature)] });
+
+        quote! { #test_only #feature }
+    }
+
+    // Returns the error type of `builder()` if the default value of any field is fallible.
+    // Defaults to a boxed `std::error::Error` which the error of every `try_default` converts into.
+    fn builder_error(&self) -> Option<proc_macro2::TokenStream> {
+        if !self
+            .def_fields
+            .iter()
+            .any(|field| self.f_attrs[field].try_default().is_some())
+        {
+            return None;
+        }
+
+        match self.s_attrs.error() {
+            Some(error) => Some(quote! { #error }),
+            None => Some(quote! {
+                ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync>
+            }),
+        }
+    }
+
+    // Returns the return type and the body of a function creating a builder of type `ty` using `init`.
+    // With a fallible default value, they're wrapped in a `Result`.
+    fn builder_fn(
+        &self,
+        ty: proc_macro2::TokenStream,
+        init: proc_macro2::TokenStream,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        match self.builder_error() {
+            Some(error) => (
+                quote! { ::core::result::Result<#ty, #error> },
+                quote! { ::core::result::Result::Ok(#init) },
+            ),
+            None => (ty, init),
+        }
+    }
+
+    // Returns the `{Builder}Init` type alias naming the builder in its initial state, which is what `builder()` returns.
+    // It lets users store a fresh builder without spelling out its const generic parameters.
+    // Type aliases don't enforce bounds, so only the names of the lifetime and type parameters are declared.
+    fn init_alias(&self) -> proc_macro2::TokenStream {
+        let init_ident = format_ident!("{}Init", self.b_ident);
+        let init_const_p = self.st_const_p.iter().filter_map(|param| match param {
+            syn::GenericParam::Const(syn::ConstParam { ident, ty, .. }) => {
+                Some(quote! { const #ident: #ty })
+            }
+            _ => None,
+        });
+
+        // Define these to be able to interpolate in quote.
+        let b_ident = &self.b_ident;
+        let st_lifetime_pn = &self.st_lifetime_pn;
+        let st_const_pn = &self.st_const_pn;
+        let st_type_pn = &self.st_type_pn;
+        let all_false = &self.all_false;
+        let items_cfg = self.items_cfg();
+
+        quote! {
+            #items_cfg
+            pub type #init_ident<#(#st_lifetime_pn,)* #(#init_const_p,)* #(#st_type_pn,)*> =
+                #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_false,)* #(#st_type_pn,)*>;
+        }
+    }
+
+    // Returns the statement that repairs the built struct using `#[builder(repair = repair)]`, if any.
+    fn repair(&self) -> Option<proc_macro2::TokenStream> {
+        let repair = self.s_attrs.repair()?;
+
+        Some(quote! { let built = (#repair)(built); })
+    }
+
+    // Returns whether a setter of the builder is named `name`, in which case a method of the builder
+    // with the same name is omitted since Rust doesn't support function overloading.
+    fn has_setter_named(&self, name: &str) -> bool {
+        let mut fields = self
+            .req_fields
+            .iter()
+            .chain(&self.opt_fields)
+            .chain(&self.def_fields);
+
+        fields.any(|&field| {
+            let mut setter_idents = self.alias_idents(field);
+            setter_idents.extend(self.value_setter_ident(field));
+            setter_idents.extend(self.extend_ident(field));
+            setter_idents.extend(self.replace_ident(field));
+
+            self.f_attrs[field].repeated().map(String::as_str) == Some(name)
+                || setter_idents.iter().any(|ident| ident == name)
+        })
+    }
+
+    // Returns the `apply` method of the builder, which passes the builder to a closure and returns what it returns.
+    // It lets users branch in the middle of a chain of setters, e.g. `.apply(|b| if cond { b.x(1) } else { b })`,
+    // since the closure can return any state of the builder.
+    // It's omitted if a setter is named `apply`.
+    fn apply_fn(&self) -> Option<proc_macro2::TokenStream> {
+        if self.has_setter_named("apply") {
+            return None;
+        }
+
+        Some(quote! {
+            pub fn apply<__F, __R>(self, f: __F) -> __R
+            where
+                __F: ::core::ops::FnOnce(Self) -> __R,
+            {
+                f(self)
+            }
+        })
+    }
+
     pub fn generate(self) -> Result<proc_macro2::TokenStream, Error> {
+        if self.s_attrs.runtime_mode() {
+            return self.runtime_builder();
+        }
+
         let req_setters = self.req_setters()?;
         let opt_setters = self.opt_setters()?;
         let def_setters = self.def_setters()?;
+        let once_setters = self.once_setters()?;
+        let setters_trait = self.setters_trait(opt_setters.iter().chain(&def_setters));
 
         let (guard_traits, guard_trait_idents) = self.guards();
+        let complete_trait = self.complete_trait(&guard_trait_idents);
+        let conversion_trait = self.conversion_trait(&guard_trait_idents);
         let default_trait = self.default_trait();
+        let builder_default_trait = self.builder_default_trait();
+        let with_required = self.with_required();
+        let into_builder = self.reverse_conversion_trait();
+        let clone_trait = self.clone_trait();
+        let debug_trait = self.struct_debug_trait();
+        let slot_type = self.slot_type();
+        let check_error_type = self.check_error_type();
+        let partial_fn = self.partial_fn();
+        let partial_type = self.partial_type();
+        let apply_fn = self.apply_fn();
+        let validate_fn = self.validate_fn();
+        let init_alias = self.init_alias();
+        let build_keep_fn = self.build_keep_fn(&guard_trait_idents);
+        let build_pairs_fn = self.build_pairs_fn();
+        let pairs_constructor = self.pairs_constructor()?;
+        let state_enum = self.state_enum()?;
+        let erase_state_fn = self.erase_state_fn();
+        let req_defaults = self.req_values(|field| self.req_take_or_default(field));
+        let req_default_bounds = self.req_default_bounds();
+        let struct_clone_bound = {
+            let s_ident = &self.s_ident;
+            let ty_generics = &self.ty_generics;
+
+            trivial_bound(
+                quote! { #s_ident #ty_generics },
+                quote! { ::core::clone::Clone },
+            )
+        };
+        let mut_builder = self.mut_builder()?;
+        let alloc = self.alloc();
+        let items_cfg = self.items_cfg();
+        let repair = self.repair();
+        let deprecated = self
+            .s_attrs
+            .deprecated()
+            .map(|note| quote! { #[deprecated = #note] });
+        let (builder_ty, builder_init) = {
+            let b_ident = &self.b_ident;
+            let st_lifetime_pn = &self.st_lifetime_pn;
+            let st_const_pn = &self.st_const_pn;
+            let st_type_pn = &self.st_type_pn;
+            let all_false = &self.all_false;
+            let b_inits = &self.b_inits;
+
+            self.builder_fn(
+                quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_false,)* #(#st_type_pn,)*> },
+                quote! { #b_ident { #(#b_inits),* } },
+            )
+        };
+
+        let from_parts_fn = self.restore_fn(&builder_ty);
+        let extend_trait = {
+            let b_ident = &self.b_ident;
+            let st_lifetime_p = &self.st_lifetime_p;
+            let st_const_p = &self.st_const_p;
+            let st_type_p = &self.st_type_p;
+            let st_lifetime_pn = &self.st_lifetime_pn;
+            let st_const_pn = &self.st_const_pn;
+            let st_type_pn = &self.st_type_pn;
+            let b_const_p = &self.b_const_p;
+            let b_const_pn = &self.b_const_pn;
+
+            self.extend_trait(
+                quote! { <#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)*> },
+                quote! { #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)*> },
+                true,
+            )?
+        };
+
+        let build_into = if repair.is_some() {
+            quote! { slot.write(self.build()) }
+        } else {
+            let s_ident = &self.s_ident;
+            let opt_moves = &self.opt_moves;
+            let def_builds = &self.def_builds;
+            let req_unwraps = &self.req_unwraps;
+            let def_computes = &self.def_computes;
+
+            quote! {
+                let built = slot.write(unsafe {
+                    #s_ident {
+                        #(#opt_moves,)*
+                        #(#def_builds,)*
+                        #(#req_unwraps,)*
+                    }
+                });
+
+                #(#def_computes)*
+
+                built
+            }
+        };
 
         let (
             b_ident,
             s_ident,
-            all_false,
+            _all_false,
             impl_generics,
             ty_generics,
             where_clause,
@@ -232,14 +740,19 @@ impl<'a> Generator<'a> {
             _req_fields,
             _opt_fields,
             _def_fields,
+            _array_slots,
+            _required_options,
+            _once_fields,
             b_const_pn,
             b_const_p,
             b_fields,
-            b_inits,
+            _b_inits,
             _req_moves,
             opt_moves,
-            def_moves,
+            _def_moves,
             req_unwraps,
+            def_builds,
+            def_computes,
         ) = (
             self.b_ident,
             self.s_ident,
@@ -256,6 +769,9 @@ impl<'a> Generator<'a> {
             self.req_fields,
             self.opt_fields,
             self.def_fields,
+            self.array_slots,
+            self.required_options,
+            self.once_fields,
             self.b_const_pn,
             self.b_const_p,
             self.b_fields,
@@ -264,21 +780,36 @@ impl<'a> Generator<'a> {
             self.opt_moves,
             self.def_moves,
             self.req_unwraps,
+            self.def_builds,
+            self.def_computes,
         );
 
         Ok(quote! {
+            #items_cfg
             pub struct #b_ident<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)*> #where_clause {
                 #(#b_fields),*
             }
 
+            #init_alias
+
+            #items_cfg
             impl #impl_generics #s_ident #ty_generics #where_clause {
-                pub fn builder() -> #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#all_false,)* #(#st_type_pn,)*> {
-                    #b_ident {
-                        #(#b_inits),*
-                    }
+                #[must_use]
+                #deprecated
+                pub fn builder() -> #builder_ty {
+                    #builder_init
                 }
+
+                #from_parts_fn
             }
 
+            #builder_default_trait
+            #with_required
+            #into_builder
+            #check_error_type
+            #pairs_constructor
+
+            #items_cfg
             impl<#(#st_lifetime_p,)* #(#st_const_p,)* #(#b_const_p,)* #(#st_type_p,)*>
                 #b_ident<#(#st_lifetime_pn,)* #(#st_const_pn,)* #(#b_const_pn,)* #(#st_type_pn,)* >
                 #where_clause
@@ -286,22 +817,92 @@ impl<'a> Generator<'a> {
                 #(#req_setters)*
                 #(#opt_setters)*
                 #(#def_setters)*
+                #partial_fn
+                #apply_fn
+                #validate_fn
+                #erase_state_fn
 
+                #[must_use]
+                #[inline]
                 fn build(self) -> #s_ident #ty_generics
                     where Self: #(#guard_trait_idents)+*
                 {
-                    unsafe {
+                    #[allow(unused_mut)]
+                    let mut built = unsafe {
                         #s_ident {
                             #(#opt_moves,)*
-                            #(#def_moves,)*
+                            #(#def_builds,)*
                             #(#req_unwraps,)*
                         }
-                    }
+                    };
+
+                    #(#def_computes)*
+                    #repair
+
+                    built
+                }
+
+                // Like with `build`, the struct is created and then moved into the slot, unless the compiler elides the move.
+                // The fields with `default_from` are computed in the slot. A `repair` takes the struct by value,
+                // so the struct is built and repaired first in that case.
+                fn build_into<'__slot>(self, slot: &'__slot mut ::core::mem::MaybeUninit<#s_ident #ty_generics>) -> &'__slot mut #s_ident #ty_generics
+                    where Self: #(#guard_trait_idents)+*
+                {
+                    #build_into
+                }
+
+                #[must_use]
+                fn build_boxed(self) -> #alloc::boxed::Box<#s_ident #ty_generics>
+                    where Self: #(#guard_trait_idents)+*
+                {
+                    #alloc::boxed::Box::new(self.build())
+                }
+
+                #build_keep_fn
+                #build_pairs_fn
+
+                #[must_use]
+                fn build_n(self, n: usize) -> #alloc::vec::Vec<#s_ident #ty_generics>
+                    where
+                        Self: #(#guard_trait_idents)+*,
+                        #struct_clone_bound
+                {
+                    #alloc::vec![self.build(); n]
+                }
+
+                // Unlike `build`, this is available in every state. The required fields that are not set
+                // get their default value.
+                #[must_use]
+                fn build_with_defaults(self) -> #s_ident #ty_generics
+                    where #(#req_default_bounds),*
+                {
+                    #[allow(unused_mut)]
+                    let mut built = #s_ident {
+                        #(#opt_moves,)*
+                        #(#def_builds,)*
+                        #(#req_defaults,)*
+                    };
+
+                    #(#def_computes)*
+                    #repair
+
+                    built
                 }
             }
 
+            #(#once_setters)*
+            #extend_trait
+            #setters_trait
+            #state_enum
             #(#guard_traits)*
+            #complete_trait
+            #conversion_trait
             #(#default_trait)*
+            #(#clone_trait)*
+            #(#debug_trait)*
+            #partial_type
+            #slot_type
+            #(#mut_builder)*
         })
     }
 }
@@ -1,3 +1,5 @@
+use quote::{quote, ToTokens};
+
 // Sometimes we only need the name of a generic parameter.
 // For example in `T: std::fmt::Display`, the whole thing is
 // a generic parameter but we want to extract the `T` from it.
@@ -82,3 +84,53 @@ pub fn split_params<'a>(
 
     (lifetimes, consts, types)
 }
+
+// Replaces every lifetime in `tokens` with `'__b`.
+//
+// Bounds on field types that only differ in their lifetimes, like `&'a T` and `&'b T`, are ambiguous for the
+// compiler. So the bounds are put on the types with the lifetimes erased, and the higher-ranked
+// `for<'__b>` makes them hold for any lifetime.
+pub fn erase_lifetimes(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    use proc_macro2::{Group, Ident, TokenTree};
+
+    let mut erased = vec![];
+    let mut is_lifetime = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                is_lifetime = true;
+                erased.push(TokenTree::Punct(punct));
+            }
+            TokenTree::Ident(ident) if is_lifetime => {
+                is_lifetime = false;
+                erased.push(TokenTree::Ident(Ident::new("__b", ident.span())));
+            }
+            TokenTree::Group(group) => {
+                is_lifetime = false;
+                let mut erased_group =
+                    Group::new(group.delimiter(), erase_lifetimes(group.stream()));
+                erased_group.set_span(group.span());
+                erased.push(TokenTree::Group(erased_group));
+            }
+            token => {
+                is_lifetime = false;
+                erased.push(token);
+            }
+        }
+    }
+
+    erased.into_iter().collect()
+}
+
+// Returns the bound requiring `ty` to implement `trait_path`, like `for<'__b> String: Clone`.
+//
+// The compiler checks a bound on a type which is not generic up front, so a bound that doesn't hold makes it reject the
+// whole impl or method rather than just leave it unavailable. A higher-ranked bound is only checked where it's used.
+// Its `'__b` is also the lifetime that `erase_lifetimes` puts in, so the bound of an erased type holds for any lifetime.
+pub fn trivial_bound(
+    ty: impl ToTokens,
+    trait_path: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! { for<'__b> #ty: #trait_path }
+}
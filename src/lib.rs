@@ -35,6 +35,20 @@
 //! ```
 //! As you can see, `first_name` and `last_name` are required fields, `age` is optional, and `employed` takes a default value of `false`.
 //! As we mentioned, in order to call `build`, you have to at least provide values for `first_name` and `last_name`.
+//! The builder also implements `Default`, so `PersonBuilder::default()` is the same as `Person::builder()`.
+//!
+//! The derive doesn't change the fields of the struct. An optional field which is never set is built as `None`,
+//! so other derives see the real `Option`. For example, serde's `#[serde(skip_serializing_if = "Option::is_none")]`
+//! leaves it out when serializing.
+//!
+//! The struct may also be `#[non_exhaustive]`. The builder creates it in the crate of the struct, so other crates can
+//! still build it using the builder, e.g. through `PersonBuilderComplete::build` or `Person::from(builder)`.
+//!
+//! If you already have the values of all the required fields, `PersonBuilder::with_required(first_name, last_name)` takes them
+//! in the order of their declaration and returns a builder which is ready to build, so only the optional and default fields are left to set.
+//!
+//! With `#[builder(into_builder)]`, the builder implements `From<Person>` in that same state. So an existing value
+//! can be turned back into a builder, changed, and built again.
 //!
 //! # Features
 //! ## Repeated setters
@@ -58,8 +72,103 @@
 //! The builder will create another setter function named `arg` alongside the `args` function that was going to be generated anyway.
 //! **Note** that if the name provided for the repeated setter is the same name as the field itself,
 //! only the repeated setter will be provided by the builder since Rust does not support function overloading.
+//! Use `#[builder(each = "args", keep_collection_setter)]` to keep the setter of the whole `Vec` under the `args_all` name.
 //! For example if in the example above the repeated setter was named `args`, the setter that takes a `Vec` wouldn't be provided.
 //!
+//! To name the repeated setter after the field, use the bare `#[builder(each)]`. The setter is then named after the singular
+//! of the field, which is found by stripping its plural suffix, e.g. `item` for `items`, `entry` for `entries`, and `prefix` for `prefixes`.
+//! Words ending in `sses`, `xes`, `ches`, or `shes` lose their `es`, and other words only lose their `s`. When that's
+//! ambiguous, like for `status` or `data`, or the singular is a keyword, like for `types` or `matches`, it's named after the field
+//! followed by `_item`, e.g. `status_item`. Only these simple plurals are recognized, so give the name explicitly for the
+//! others, e.g. `#[builder(each = "cache")]` for `caches` which would otherwise become `cach`.
+//!
+//! The builder also creates a setter named `args_extend` which appends all the items of an iterator to the `Vec`.
+//! You can rename it using the `#[builder(extend = "name")]` attribute:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Input<'a> {
+//!     #[builder(each = "arg", extend = "more_args")]
+//!     args: Vec<&'a str>
+//! }
+//!
+//! let input = Input::builder().arg("arg1").more_args(["arg2", "arg3"]).build();
+//!
+//! assert_eq!(input.args, vec!["arg1", "arg2", "arg3"]);
+//! ```
+//!
+//! For `Option<Vec<T>>` fields, the builder also creates a setter named `set_` followed by the name of the field,
+//! e.g. `set_args`, which replaces the whole `Option`. Passing `None` to it clears the items pushed so far.
+//!
+//! Besides `Vec`, the repeated setters support `VecDeque`, `LinkedList`, `HashSet`, `BTreeSet`, and `BinaryHeap` fields,
+//! as well as `HashMap` and `BTreeMap` fields whose repeated setter takes a `(key, value)` tuple and inserts it.
+//!
+//! To run some bookkeeping on each item, use `#[builder(each = "arg", on_push = hook)]`.
+//! The repeated setter of a `Vec` calls `hook`, a function or a closure, with a reference to each item after pushing it.
+//!
+//! A collection can start with some items using `#[builder(default = vec![..], each = "arg")]`.
+//! The repeated setter appends to them, and the setter of the whole collection replaces them.
+//! With `#[builder(value = vec![..], each = "arg")]` instead, only the repeated setter and the extend setter are provided,
+//! so the items of the value are always kept.
+//!
+//! When only one field has a repeated setter, the builder also implements `Extend` for its items, so it can be filled by
+//! generic code like `builder.extend(iter)`. Since `Extend` takes the builder by mutable reference, pushing to a required field
+//! can't change its type. So for a required field, only the builders of `mode = runtime` and `both_modes` implement it.
+//!
+//! ## Setter names
+//! By default, the setters are named after the fields. You can rename the setter of a field using `#[builder(name = "name")]`,
+//! or prefix all setters using `#[builder(setter_prefix = "prefix")]` on the struct. Explicit names are never prefixed. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(setter_prefix = "with_")]
+//! struct Person {
+//!     name: String,
+//!
+//!     #[builder(name = "aged")]
+//!     age: usize,
+//! }
+//!
+//! let person = Person::builder().with_name("Foo".to_string()).aged(18).build();
+//!
+//! assert_eq!(person.name, "Foo");
+//! assert_eq!(person.age, 18);
+//! ```
+//!
+//! To offer several names for the same setter, give `name` a list like `#[builder(name = [set_age, with_age, age])]`.
+//! The first one names the setter and the others are aliases which behave exactly like it.
+//! To add aliases while keeping the name of the setter, use `#[builder(alias = "with_age")]` or `#[builder(alias = [with_age, aged])]`.
+//!
+//! To namespace the setters by the struct, use `#[builder(setter_prefix = type)]` which prefixes them with the name of
+//! the struct in snake case followed by `_`, e.g. `http_server_port` for the `port` field of `HttpServer`.
+//!
+//! To mirror a schema with another naming convention, use `#[builder(rename_all = "camelCase")]` on the struct.
+//! It converts the names of all setters, e.g. `max_size` becomes `maxSize`, using the same case names as serde:
+//! `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, and `SCREAMING_SNAKE_CASE`. Explicit names, like the ones given by `name`, `each`, or `alias`, are never converted.
+//!
+//! The setters are `pub`. To restrict the setters of a field, give it a visibility like `#[builder(vis = "pub(crate)")]`,
+//! or `#[builder(vis = "")]` to only let the module of the struct call them. Such setters are left out of the setters trait.
+//!
+//! ## Per-index setters
+//! Use `#[builder(per_index)]` on a required array field to set each of its elements separately.
+//! The setters are named after the field followed by the index, and `build` can only be called once all elements are set. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Point {
+//!     #[builder(per_index)]
+//!     coords: [f64; 3],
+//! }
+//!
+//! let point = Point::builder().coords_0(1.0).coords_2(3.0).coords_1(2.0).build();
+//!
+//! assert_eq!(point.coords, [1.0, 2.0, 3.0]);
+//! ```
+//! **Note** that the length of the array must be an integer literal.
+//!
 //! ## Default values
 //! You can provide default values for fields and make them non-required. If the field is a primitive or a `String`,
 //! you can specify the default value in the `#[builder(default)]` attribute, but if the field is not a primitive, it must implement the `Default` trait. For example:
@@ -96,6 +205,297 @@
 //! assert_eq!(position.offset, 0);
 //! ```
 //!
+//! Fixed-size arrays of any length can use `#[builder(default)]` as long as their elements implement `Default`.
+//!
+//! The default value can also be an arbitrary expression, e.g. `#[builder(default = 2 * SIZE)]`.
+//!
+//! If computing the default value is expensive, you can use `once_cell` to compute it only once.
+//! The value is then shared across all builders by cloning it, so the field must implement `Clone`:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! fn default_hosts() -> Vec<String> {
+//!     vec!["localhost".to_string()]
+//! }
+//!
+//! #[derive(Builder)]
+//! struct Config {
+//!     #[builder(default = default_hosts, once_cell)]
+//!     hosts: Vec<String>,
+//! }
+//!
+//! let config = Config::builder().build();
+//!
+//! assert_eq!(config.hosts, vec!["localhost".to_string()]);
+//! ```
+//! **Note** that the value is stored in a `static`, so the type of the field cannot depend on the generic parameters of the struct.
+//!
+//! A bare path given to `default` is the value itself, e.g. `default = MAX_HOSTS`, except with `once_cell` where it names
+//! the function initializing the value, like `default_hosts` above. To share a constant, use an expression such as `default = MAX_HOSTS.clone()`.
+//!
+//! A default value can also be computed from the other fields using `default_from`. It takes a closure or function
+//! which receives a reference to the built struct. Computed fields are evaluated in the order of their declaration,
+//! and the ones not computed yet hold `Default::default()`, so the type of the field must implement `Default`:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Rect {
+//!     width: usize,
+//!     height: usize,
+//!
+//!     #[builder(default_from = |rect| rect.width * rect.height)]
+//!     area: usize,
+//! }
+//!
+//! let rect = Rect::builder().width(2).height(3).build();
+//!
+//! assert_eq!(rect.area, 6);
+//! ```
+//!
+//! If computing a default value can fail, use `try_default` with an expression returning a `Result`.
+//! Then `builder()` returns a `Result` too, whose error type is set by `#[builder(error = "Type")]`
+//! and defaults to `Box<dyn std::error::Error + Send + Sync>`. The error of every `try_default` must convert into it:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Server {
+//!     #[builder(try_default = "8080".parse::<u16>())]
+//!     port: u16,
+//! }
+//!
+//! let server = Server::builder().unwrap().build();
+//!
+//! assert_eq!(server.port, 8080);
+//! ```
+//! **Note** that neither the builder nor the struct implements `Default` when a default value is fallible.
+//!
+//! ## Wrapping setters
+//! Setters of `Box`, `Rc`, and `Arc` fields can take the inner type and wrap it in the pointer themselves.
+//! They take anything that converts into the pointer, so a `&str` works for an `Arc<str>` as well. For example:
+//! ```rust
+//! use std::sync::Arc;
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct User {
+//!     #[builder(setter = wrap)]
+//!     name: Arc<str>,
+//!
+//!     #[builder(setter = wrap)]
+//!     nickname: Option<Arc<String>>,
+//! }
+//!
+//! let user = User::builder().name("Foo").nickname("Bar".to_string()).build();
+//!
+//! assert_eq!(&*user.name, "Foo");
+//! assert_eq!(user.nickname.as_deref().map(String::as_str), Some("Bar"));
+//! ```
+//!
+//! ## Into setters
+//! Use the `#[builder(into)]` attribute to make the setter of a field take any value that converts into its type. For example:
+//! ```rust
+//! use std::path::{Path, PathBuf};
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Config {
+//!     #[builder(into)]
+//!     name: String,
+//!
+//!     #[builder(into)]
+//!     root: Option<PathBuf>,
+//! }
+//!
+//! let config = Config::builder().name("app").root("/srv").build();
+//! assert_eq!(config.name, "app");
+//!
+//! // `String` and `PathBuf` fields also get a `_str` and a `_path` setter which take a `&str` and a `&Path`.
+//! // They're handy when the type of the argument can't be inferred, e.g. when calling `as_ref()`.
+//! let name = String::from("app");
+//! let config = Config::builder().name_str(name.as_ref()).root_path(Path::new("/srv")).build();
+//! assert_eq!(config.root.as_deref(), Some(Path::new("/srv")));
+//! ```
+//!
+//! For `String` fields whose sources implement `Display` but not `Into<String>`, like numbers, use the `#[builder(to_string)]`
+//! attribute instead. The setter then takes any `impl Display` and stores its `to_string()`.
+//!
+//! To make every setter take `impl Into`, put `#[builder(into)]` on the struct instead. Fields can opt out using `#[builder(no_into)]`,
+//! which is handy for integer fields since an integer literal like `8080` can't be inferred to be a `u16` through `Into`.
+//! Fields with `#[builder(to_string)]` are not affected.
+//!
+//! The repeated setters of these fields take an `impl Into` of the item, so `#[builder(into, each = "arg")]` on a `Vec<String>`
+//! pushes a `&str` one at a time.
+//!
+//! To only make the setters of `String` values take a `&str`, put `#[builder(str_setters)]` on the struct. The setters then store
+//! its `to_owned()`, and since their argument is not generic, a `&String` works as well. Fields with `#[builder(into)]` or
+//! `#[builder(to_string)]` are not affected.
+//!
+//! Setters of `Cow` fields, like `Cow<'a, str>`, take `impl Into` without any attribute, so they accept both borrowed
+//! and owned values, e.g. a `&str` or a `String`. Use `#[builder(no_into)]` to make them take a `Cow` as is.
+//!
+//! ## Setting only `Some` values
+//! Use `#[builder(setter = set_if_some)]` on optional and default fields to make their setter take an `Option`
+//! and leave the field untouched if it's `None`. This is handy for forwarding values that may be missing. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Config {
+//!     #[builder(setter = set_if_some)]
+//!     name: Option<String>,
+//!
+//!     #[builder(default = 8080, setter = set_if_some)]
+//!     port: u16,
+//! }
+//!
+//! let config = Config::builder().name(None).port(None).build();
+//!
+//! assert_eq!(config.name, None);
+//! assert_eq!(config.port, 8080);
+//!
+//! let config = Config::builder().port(Some(80)).port(None).build();
+//! assert_eq!(config.port, 80);
+//! ```
+//!
+//! ## Checking values
+//! Use the `#[builder(check = check)]` attribute to validate the value passed to the setter of a field.
+//! `check` is a function or a closure which takes a reference to the value and returns a `Result<(), E>`.
+//! The setter then returns a `Result` of the builder, and the error is a generated enum named after the builder followed by `Error`.
+//! It has a variant per checked field, named after the field in PascalCase, which carries the error of the check.
+//! `E` defaults to `&'static str` and is set using `#[builder(check_error = "Type")]`. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Server {
+//!     #[builder(check = |port: &u16| if *port < 1024 { Err("reserved port") } else { Ok(()) })]
+//!     port: u16,
+//! }
+//!
+//! let server = Server::builder().port(8080).unwrap().build();
+//! assert_eq!(server.port, 8080);
+//!
+//! match Server::builder().port(80) {
+//!     Err(ServerBuilderError::Port(error)) => assert_eq!(error, "reserved port"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//! The error implements `Debug`, `Display`, and `std::error::Error` when the errors of the checks do.
+//!
+//! The check is evaluated in the setter on every call, so a closure can't capture the local variables of the caller.
+//! It can capture constants and statics, or the locals of a block building it, like `check = { let max = 10; move |v: &u8| ... }`.
+//! Closures that mutate what they capture are supported, but their state doesn't carry over between calls.
+//!
+//! To report every invalid value at once, e.g. in a form, call `validate` on the builder. It runs the checks against the values
+//! set so far, including default values which the setters never check, and returns the errors of all the failing checks in a `Vec`.
+//!
+//! To compare the value to other fields, e.g. that a `max` is not below a `min`, use `#[builder(check_with = check)]` instead.
+//! The check then also takes a reference to the builder, whose optional fields and required fields, unless `storage = maybe_uninit`, are `Option`s that are `None` until set,
+//! like `check_with = |builder, max: &u32| match builder.min { Some(min) if min > *max => Err("below min"), _ => Ok(()) }`.
+//! The check runs before the value is stored, so it only sees the fields set before, and a field set afterwards is not checked against it.
+//! Call `validate` before building to run the checks against all the values once they're set.
+//!
+//! ## Repairing values
+//! Use the `#[builder(repair = repair)]` attribute to turn every built struct into a valid one, e.g. by clamping its fields.
+//! `repair` is a function or a closure which takes the built struct and returns the repaired one, so `build` never fails. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(repair = |volume: Volume| Volume { level: volume.level.min(100) })]
+//! struct Volume {
+//!     level: u8,
+//! }
+//!
+//! let volume = Volume::builder().level(150).build();
+//!
+//! assert_eq!(volume.level, 100);
+//! ```
+//!
+//! ## Required optional fields
+//! Use the `#[builder(required)]` attribute to make the builder require an `Option` field, e.g. when the field is only
+//! an `Option` for serialization. Its setter takes the inner type, and `build` wraps the value in `Some`. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Request {
+//!     #[builder(required)]
+//!     url: Option<String>,
+//! }
+//!
+//! let request = Request::builder().url("https://example.com".to_string()).build();
+//!
+//! assert_eq!(request.url, Some("https://example.com".to_string()));
+//! ```
+//!
+//! ## Setting optional fields once
+//! Use `#[builder(once)]` on an optional field to make its setter callable at most once.
+//! Like a required field, the field gets a const generic parameter of its own which its setter sets to `true`,
+//! so calling the setter again doesn't compile. Unlike a required field, it doesn't need to be set to call `build`. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Request {
+//!     url: String,
+//!
+//!     #[builder(once)]
+//!     timeout: Option<u64>,
+//! }
+//!
+//! let request = Request::builder().timeout(30).url("example.com".to_string()).build();
+//! assert_eq!(request.timeout, Some(30));
+//!
+//! // Doesn't compile:
+//! // Request::builder().timeout(30).timeout(60);
+//! ```
+//!
+//! With `each`, only the setter of the whole collection can be called once, and it replaces the items pushed before.
+//! The repeated and extend setters can be called any number of times, before or after it.
+//!
+//! It can't be combined with `setter = set_if_some` or `skip`. Neither can it be used with `#[builder(both_modes)]`
+//! or `#[builder(mode = runtime)]`, since their builders don't track which fields are set.
+//!
+//! ## Nested builders
+//! When the type of a required field also derives `Builder`, use `#[builder(flatten)]` to configure its builder in place.
+//! The field gets another setter, named after its setter followed by `_with`, which takes a closure. The closure gets the builder
+//! of the field in its initial state and returns it once all of its required fields are set:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Address {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! #[derive(Builder)]
+//! struct Server {
+//!     name: String,
+//!
+//!     #[builder(flatten)]
+//!     address: Address,
+//! }
+//!
+//! let server = Server::builder()
+//!     .name("app".to_string())
+//!     .address_with(|b| b.host("localhost".to_string()).port(8080))
+//!     .build();
+//!
+//! assert_eq!(server.address.port, 8080);
+//! ```
+//!
+//! The builder of the field is found through the `{Builder}Init` alias and the `{Builder}Complete` trait, named after the
+//! last segment of the path of the type. So its generic arguments must be given in the order of the alias, and the builder
+//! can't have fallible default values. The setter is only provided by the typestate builder.
+//!
+//! Likewise, when the items of a field with a repeated setter derive `Builder`, use `#[builder(each = "child", each_builder)]`
+//! to build each item in place. The field gets another repeated setter, named after the repeated setter followed by `_with`,
+//! e.g. `.child_with(|b| b.name("leaf".to_string()))`, which builds the item out of the builder returned by the closure and pushes it.
+//!
 //! ## Skipping Fields
 //! You can prevent the builder from providing setters for **optional** and **default** fields. For example:
 //! ```rust compile_fail
@@ -118,6 +518,385 @@
 //! }
 //! ```
 //!
+//! Required fields can't be skipped, unless they're given a fixed value using `#[builder(value = expr)]`.
+//! Such a field is computed by the builder and has no setter.
+//!
+//! If computing the value can fail, use `#[builder(try_value = expr)]` with an expression returning a `Result`.
+//! Like with `try_default`, `builder()` then returns a `Result`.
+//!
+//! `PhantomData` fields are skipped without any attribute, as if they had `#[builder(value = PhantomData)]`.
+//!
+//! ## Conditionally compiled fields
+//! Fields behind a `#[cfg(...)]` attribute are supported. The builder only provides their setters when they are compiled in,
+//! and a required field that is compiled out is not needed to call `build`.
+//!
+//! ## Complete builders
+//! The builder implements the sealed `{Builder}Complete` trait once all required fields are set.
+//! It lets generic code accept a builder that is ready to be built without naming its const generic parameters. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Job {
+//!     name: String,
+//!     retries: Option<usize>,
+//! }
+//!
+//! fn finish(builder: impl JobBuilderComplete<Output = Job>) -> Job {
+//!     builder.build()
+//! }
+//!
+//! let job = finish(Job::builder().name("backup".to_string()));
+//!
+//! assert_eq!(job.name, "backup");
+//! ```
+//!
+//! The struct also implements `From` for the complete builder, so `let job: Job = builder.into();` works as well.
+//!
+//! The builder in its initial state, as returned by `builder()`, can be named using the `{Builder}Init` type alias,
+//! e.g. `JobBuilderInit`. It takes the lifetime, const, and type parameters of the struct in this order, like the builder.
+//!
+//! ## Branching in a chain
+//! Every builder has an `apply` method which passes the builder to a closure and returns what the closure returns.
+//! It comes in handy to set fields conditionally without breaking the chain of setters:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! struct Server {
+//!     host: String,
+//!     port: Option<u16>,
+//! }
+//!
+//! let custom_port = true;
+//! let server = Server::builder()
+//!     .apply(|b| if custom_port { b.port(8080) } else { b })
+//!     .host("localhost".to_string())
+//!     .build();
+//!
+//! assert_eq!(server.port, Some(8080));
+//! ```
+//!
+//! The closure may also set required fields, since it can return the builder in any state.
+//! If a setter is already named `apply`, the method is not generated.
+//!
+//! ## Setters trait
+//! Use `#[builder(setters_trait)]` to also provide the setters of optional and default fields through a `{Struct}Setters` trait.
+//! It's implemented for the builder in every state since these setters don't change it, so generic code can set them on any builder:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(setters_trait)]
+//! struct Server {
+//!     host: String,
+//!     port: Option<u16>,
+//! }
+//!
+//! fn with_defaults<B: ServerSetters>(builder: B) -> B {
+//!     builder.port(8080)
+//! }
+//!
+//! let server = with_defaults(Server::builder()).host("localhost".to_string()).build();
+//!
+//! assert_eq!(server.port, Some(8080));
+//! ```
+//!
+//! The trait takes the generic parameters of the struct. The setters of required fields change the type of the builder,
+//! so they're not part of it.
+//!
+//! ## Builder state enum
+//! Use `#[builder(state_enum)]` to get a `{Builder}State` enum with a variant wrapping the builder in each of its states,
+//! so builders at different progress can be stored together. `into_state` erases the state of a builder into the enum,
+//! and matching on it gives back the typed builder:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(state_enum)]
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let drafts: Vec<ServerBuilderState> = vec![
+//!     Server::builder().port(80).into_state(),
+//!     Server::builder().host("localhost".to_string()).port(8080).into_state(),
+//! ];
+//!
+//! for draft in drafts {
+//!     let server = match draft {
+//!         ServerBuilderState::WithPort(builder) => builder.host("example.com".to_string()).build(),
+//!         ServerBuilderState::WithHostAndPort(builder) => builder.build(),
+//!         _ => unreachable!(),
+//!     };
+//!
+//!     assert!(!server.host.is_empty());
+//! }
+//! ```
+//!
+//! The variants are named after the required fields and the fields with `once` that are set, e.g. `WithHostAndPort`,
+//! and the variant of the initial state is `Init`. Since there's a variant for each of their combinations,
+//! at most 6 such fields are supported. A builder with `mode = runtime` has a single state, so it gets no enum.
+//!
+//! ## Const setters
+//! Use `#[builder(const)]` to make the setters `const fn`, so they can be called in const contexts:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(const)]
+//! struct Server {
+//!     host: &'static str,
+//!     port: Option<u16>,
+//! }
+//!
+//! const fn local(builder: ServerBuilder<false>) -> ServerBuilder<true> {
+//!     builder.host("localhost").port(8080)
+//! }
+//!
+//! let server = local(Server::builder()).build();
+//!
+//! assert_eq!(server.port, Some(8080));
+//! ```
+//!
+//! Only the setters that assign their argument as is can be `const`, so the ones using `into`, `to_string`, `wrap`,
+//! `set_if_some` or a `check`, and the repeated and extend setters are not. Neither are the setters with `#[builder(trace)]`
+//! or the setters of required fields with `#[builder(storage = maybe_uninit)]`.
+//! Assigning a value drops the previous one, so only the setters of values which surely don't need to be dropped are `const`:
+//! primitives, references, pointers, `PhantomData`, and arrays, tuples, and `Option`s of them. The setters of other types,
+//! like `String`, your own structs, or a generic `T`, are regular functions. The setters of required fields and fields with `once`
+//! leave the builder partially moved, so they're only `const` when none of the fields has such a type.
+//!
+//! ## Borrowing builders
+//! Use the `#[builder(both_modes)]` attribute to also generate a builder whose setters take `&mut self`.
+//! It's created by `builder_mut` and comes in handy when the values are set conditionally or in a loop.
+//! Since it can't track the required fields in its type, its `build` function returns an error naming the first missing field. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(both_modes)]
+//! struct Command {
+//!     program: String,
+//!
+//!     #[builder(each = "arg")]
+//!     args: Vec<String>,
+//! }
+//!
+//! let mut builder = Command::builder_mut();
+//! builder.program("ls".to_string());
+//! for arg in ["-l", "-a"] {
+//!     builder.arg(arg.to_string());
+//! }
+//!
+//! let command = builder.build().unwrap();
+//! assert_eq!(command.args, vec!["-l", "-a"]);
+//!
+//! assert_eq!(Command::builder_mut().build().err(), Some("missing `program`"));
+//! ```
+//!
+//! ## Runtime-checked builders
+//! The setters of required fields change the type of the builder, which makes the signatures long and
+//! slows down compilation for structs with many required fields.
+//! Use the `#[builder(mode = runtime)]` attribute to generate a builder without const generic parameters instead.
+//! All its setters return `Self`, and `try_build` returns an error naming the first missing field. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(mode = runtime)]
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let mut builder: ServerBuilder = Server::builder().host("localhost".to_string());
+//! if builder_needs_port() {
+//!     builder = builder.port(8080);
+//! }
+//!
+//! let server = builder.try_build().unwrap();
+//! assert_eq!(server.port, 8080);
+//!
+//! assert_eq!(Server::builder().port(80).try_build().err(), Some("missing `host`"));
+//! # fn builder_needs_port() -> bool { true }
+//! ```
+//!
+//! Since the builder can't be built without the runtime check, there is no `build` function. `storage = maybe_uninit` is not supported either.
+//!
+//! ## Cloning the builder
+//! You can instruct the builder to implement `Clone` using the `#[builder(derive(Clone))]` attribute.
+//! This lets you build several similar values from a shared base. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(derive(Clone))]
+//! struct Request {
+//!     url: String,
+//!     method: String,
+//! }
+//!
+//! let base = Request::builder().url("example.com".to_string());
+//!
+//! let get = base.clone().method("GET".to_string()).build();
+//! let post = base.method("POST".to_string()).build();
+//!
+//! assert_eq!(get.url, post.url);
+//! ```
+//! The builder is only `Clone` if the types of all fields are `Clone`.
+//!
+//! ## Debugging the struct
+//! Use the `#[builder(struct_debug)]` attribute to implement `Debug` for the struct itself.
+//! Fields marked with `#[builder(secret)]` are printed as `<redacted>` and don't need to implement `Debug`. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(struct_debug)]
+//! struct Login {
+//!     user: String,
+//!
+//!     #[builder(secret)]
+//!     password: String,
+//! }
+//!
+//! let login = Login::builder().user("foo".to_string()).password("bar".to_string()).build();
+//!
+//! assert_eq!(format!("{:?}", login), r#"Login { user: "foo", password: <redacted> }"#);
+//! ```
+//!
+//! ## Inspecting the builder
+//! Use the `#[builder(partial)]` attribute to give the builder a `partial` function which returns a snapshot of the values set so far.
+//! The snapshot mirrors the struct with every field wrapped in an `Option`. It is named after the struct with a `Partial` prefix and implements `Debug`.
+//! Default values count as set, while fields with `default_from` are only set if their setter was called.
+//! The values are cloned, so the builder can still be used afterwards. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder)]
+//! #[builder(partial)]
+//! struct Login {
+//!     user: String,
+//!
+//!     #[builder(secret)]
+//!     password: String,
+//!
+//!     #[builder(default = 3)]
+//!     attempts: usize,
+//! }
+//!
+//! let builder = Login::builder().user("foo".to_string());
+//! let partial: PartialLogin = builder.partial();
+//!
+//! assert_eq!(partial.user.as_deref(), Some("foo"));
+//! assert_eq!(partial.password, None);
+//! assert_eq!(
+//!     format!("{:?}", partial),
+//!     r#"PartialLogin { user: Some("foo"), password: <redacted>, attempts: Some(3) }"#
+//! );
+//!
+//! let login = builder.password("bar".to_string()).build();
+//! assert_eq!(login.user, "foo");
+//! ```
+//!
+//! A snapshot can be turned back into a builder using `from_parts`, e.g. `Login::from_parts(partial)`. It returns the builder of `builder()`
+//! with the optional and default fields restored. Setting a required field changes the state of the builder, so the required fields must be set again.
+//! The builder of `#[builder(mode = runtime)]` has no state, so every field is restored.
+//!
+//! ## Building many values
+//! When the struct implements `Clone`, the builder also provides `build_n` which builds the struct once
+//! and returns `n` clones of it. This comes in handy for test fixtures. For example:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder, Clone)]
+//! struct Job {
+//!     name: String,
+//!     retries: Option<usize>,
+//! }
+//!
+//! let jobs = Job::builder().name("backup".to_string()).build_n(3);
+//!
+//! assert_eq!(jobs.len(), 3);
+//! assert!(jobs.iter().all(|job| job.name == "backup"));
+//! ```
+//!
+//! `build_boxed` builds the struct and returns it in a `Box`, which saves wrapping the result of `build` by hand.
+//! It's a convenience only: the struct is built like with `build` and then moved into the box, so a large struct
+//! may still be copied through the stack unless the compiler optimizes the move away. Like `build`, it's generated
+//! for every builder, so there's no attribute like `#[builder(boxed_build)]` to opt into it.
+//!
+//! To build several variants of the struct from one builder, use `build_keep` which takes the builder by reference
+//! and builds the struct out of clones of its values. It's available when the type of every field implements `Clone`.
+//!
+//! To reuse storage, e.g. the slots of a pool, use `build_into` which writes the struct into a `&mut MaybeUninit` of it
+//! and returns a reference to the written struct. The slot is not dropped first, and it owns the struct afterwards.
+//! **Note** that the struct is still created before it's moved into the slot, like with `slot.write(builder.build())`,
+//! so a large struct may be copied through the stack unless the compiler optimizes the move away.
+//!
+//! `build_with_defaults` can be called before all the required fields are set. It fills the missing ones with their
+//! `Default` value, so it's only available when the type of every required field implements `Default`.
+//!
+//! The struct implements `Default` when it has no required fields. Use `#[builder(default)]` on the struct to implement it
+//! anyway, in which case the required fields get their `Default` value like with `build_with_defaults`.
+//!
+//! ## Building from pairs
+//! Use `#[builder(from_pairs)]` to also generate a `from_pairs` function which builds the struct from `(name, value)` pairs,
+//! e.g. when loading a config. It's available when the values of all the fields have the same type:
+//! ```rust
+//! use tidy_builder::Builder;
+//!
+//! #[derive(Builder, Debug)]
+//! #[builder(from_pairs)]
+//! struct Env {
+//!     home: String,
+//!     shell: Option<String>,
+//! }
+//!
+//! let env = Env::from_pairs([("home", "/root".to_string())]).unwrap();
+//! assert_eq!(env.home, "/root");
+//!
+//! let error = Env::from_pairs([("path", "/bin".to_string())]).unwrap_err();
+//! assert_eq!(error, EnvBuilderPairsError::Unknown("path"));
+//! ```
+//!
+//! The names are the names of the fields, and the values are stored as is rather than passed to the setters.
+//! Its error is `Unknown` for a name that doesn't match a field, and `Missing` for a required field that is not set.
+//! Fields with `check` or `try_default` can't be built from pairs.
+//!
+//! ## Storage of required fields
+//! The builder stores each required field in an `Option` until it's built. Use `#[builder(storage = maybe_uninit)]`
+//! to store them in a `MaybeUninit` instead, which saves the space of the `Option` discriminants.
+//! The state of the builder already proves which fields are set, so `build` moves them out as is,
+//! and dropping a builder before building it only drops the fields that were set.
+//! It can't be combined with `#[builder(both_modes)]` since the borrowing builder doesn't track which fields are set.
+//!
+//! ## `no_std`
+//! The generated code only relies on `core`, except for the methods that allocate like `build_n`.
+//! For `no_std` crates, use the `#[builder(no_std)]` attribute to take allocated types from the `alloc` crate instead of `std`.
+//! **Note** that the `alloc` crate must be declared using `extern crate alloc`, and `once_cell` is not available.
+//!
+//! ## Tracing setters
+//! Use the `#[builder(trace)]` attribute to log every setter call at the debug level using the [`log`](https://docs.rs/log) crate.
+//! This helps with finding out how a configuration was assembled. **Note** that this is behind the `trace` feature gate,
+//! and the crate using the builder must depend on `log` too.
+//!
+//! ## Test-only builders
+//! Use the `#[builder(test_only)]` attribute to only generate the builder under `#[cfg(test)]`.
+//! This is handy for building structs with private fields in tests without exposing a builder in the public API.
+//!
+//! Similarly, use the `#[builder(feature = "name")]` attribute to only generate the builder when a feature of your crate is enabled.
+//! The struct itself is always available.
+//!
+//! ## Deprecating the builder
+//! When migrating away from the builder, use the `#[builder(deprecated = "use Config::new instead")]` attribute
+//! to mark `builder()` as `#[deprecated]` with the given note, so its callers get a warning.
+//!
+//! Similarly, to phase out a field, put `#[builder(deprecated = "note")]` on it. All of its setters, including the renamed,
+//! repeated, and `_extend` setters, are then marked as `#[deprecated]` with the given note.
+//!
 //! # What if I try to call the `build` function early?
 //! tidy-builder uses special traits to hint at the missing required fields. For example:
 //! ```rust compile_fail
@@ -133,8 +912,8 @@
 //!     let foo = Foo::builder().bar(0).build();
 //! }
 //! ```
-//! On stable Rust you'll get a **compile-time** error that the trait `HasBaz` is not implemented for the struct `FooBuilder<...>`.
-//! The trait `HasBaz` indicates that `FooBuilder` **has** a value for the **`baz`** field.
+//! On stable Rust you'll get a **compile-time** error that the trait `FooHasBaz` is not implemented for the struct `FooBuilder<...>`.
+//! The trait `FooHasBaz` indicates that `FooBuilder` **has** a value for the **`baz`** field.
 //! So this trait not being implemented for `FooBuilder` means that a value is not specified for the `baz` field and that's why you cannot call the `build` function.
 //!
 //! On nightly Rust and with the help of `rustc_on_unimplemented`, the `Builder` can hint at the compiler to
@@ -185,7 +964,7 @@
 //! and it's in this state that you can call the build function(the state that all const generic paramters are `true`):
 //!
 //! The error reporting discussed in the previous section leverages these states to inform the user of the missing fields.
-//! For example `HasBar` trait will be implemented for `FooBuilder<true, P1>` , and `HasBaz` will be implemented for `FooBuilder<P0, true>`.
+//! For example `FooHasBar` trait will be implemented for `FooBuilder<true, P1>` , and `FooHasBaz` will be implemented for `FooBuilder<P0, true>`.
 //! The `build` function is guarded with a where clause to make sure the builder implements all these traits:
 //! ```rust
 //! # struct Foo {
@@ -198,20 +977,20 @@
 //! #     baz: Option<usize>,
 //! # }
 //! #
-//! # trait HasBar {}
-//! # impl<const P1: bool> HasBar for FooBuilder<true, P1> {}
+//! # trait FooHasBar {}
+//! # impl<const P1: bool> FooHasBar for FooBuilder<true, P1> {}
 //! #
-//! # trait HasBaz {}
-//! # impl<const P0: bool> HasBaz for FooBuilder<P0, true> {}
+//! # trait FooHasBaz {}
+//! # impl<const P0: bool> FooHasBaz for FooBuilder<P0, true> {}
 //! #
 //! impl<const P0: bool, const P1: bool> FooBuilder<P0, P1> {
 //!     fn build(self) -> Foo
 //!     where
-//!         Self: HasBar + HasBaz
+//!         Self: FooHasBar + FooHasBaz
 //!     {
 //!         // Safety:
 //!         //
-//!         // It's safe since HasBar and HasBaz are implemented
+//!         // It's safe since FooHasBar and FooHasBaz are implemented
 //!         // hence self.bar and self.baz both contain valid values.
 //!         unsafe {
 //!             Foo {
@@ -222,7 +1001,7 @@
 //!     }
 //! }
 //! ```
-//! So if you set the value of `bar` and not `baz`, since `HasBaz` won't be implemented for `FooBuilder<true, false>`,
+//! So if you set the value of `bar` and not `baz`, since `FooHasBaz` won't be implemented for `FooBuilder<true, false>`,
 //! you'll get a compile-time error that calling `build` is not possible.
 //!
 
@@ -245,7 +1024,10 @@ macro_rules! ret_on_err {
 
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+    let mut ast = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    // The generator never emits the struct itself, so it's fine to rewrite the types of its fields.
+    wrap::resolve_self(&mut ast);
 
     let generator = ret_on_err!(generator::Generator::new(&ast));
 
@@ -1,3 +1,5 @@
+use quote::{quote, ToTokens};
+
 use crate::err::Error;
 
 // Some types wrap around another type(their inner type). For example `Vec` wraps around `T` so does `Option`.
@@ -47,3 +49,202 @@ pub fn type_ident(wrapper: &syn::Type) -> Result<&syn::Ident, Error> {
 pub fn is_option(ty: &syn::Type) -> Option<&syn::Type> {
     wrapped_in(ty, Some("Option"))
 }
+
+// Returns the item type of a collection, which is what the repeated setters push into it.
+// It's the element type of `Vec`, `VecDeque`, `LinkedList`, `HashSet`, `BTreeSet`, and `BinaryHeap`,
+// and the `(key, value)` tuple of `HashMap` and `BTreeMap`, since they all extend from an iterator of their items.
+// Returns an error for other types since the item type of an arbitrary collection is unknown.
+pub fn collection_item(ty: &syn::Type) -> Result<syn::Type, Error> {
+    let sequence_item = [
+        "Vec",
+        "VecDeque",
+        "LinkedList",
+        "HashSet",
+        "BTreeSet",
+        "BinaryHeap",
+    ]
+    .iter()
+    .find_map(|sequence| wrapped_in(ty, Some(sequence)));
+    if let Some(item_ty) = sequence_item {
+        return Ok(item_ty.clone());
+    }
+
+    if let syn::Type::Path(syn::TypePath { path, .. }) = ty {
+        let segment = &path.segments[0];
+
+        if segment.ident == "HashMap" || segment.ident == "BTreeMap" {
+            if let syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments {
+                args,
+                ..
+            }) = &segment.arguments
+            {
+                let mut types = args.iter().filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                });
+
+                if let (Some(key_ty), Some(value_ty)) = (types.next(), types.next()) {
+                    return Ok(syn::parse_quote! { (#key_ty, #value_ty) });
+                }
+            }
+        }
+    }
+
+    Err(Error::NotCollection(ty.clone()))
+}
+
+// Returns whether the type is a `Vec`.
+pub fn is_vec(ty: &syn::Type) -> bool {
+    wrapped_in(ty, Some("Vec")).is_some()
+}
+
+// Returns the element type and the length of an array like `[T; 3]`.
+// Returns `None` if the type is not an array or its length is not an integer literal.
+pub fn array_len(ty: &syn::Type) -> Option<(&syn::Type, usize)> {
+    if let syn::Type::Array(syn::TypeArray {
+        elem,
+        len:
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(len),
+                ..
+            }),
+        ..
+    }) = ty
+    {
+        return Some((elem, len.base10_parse().ok()?));
+    }
+
+    None
+}
+
+// Returns whether the last segment of the path of the type is named `name`, e.g. `Cow` for `std::borrow::Cow<'a, str>`.
+fn named(ty: &syn::Type, name: &str) -> bool {
+    if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+        return path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name);
+    }
+
+    false
+}
+
+// Returns whether the type is a `Cow`, e.g. `Cow<'a, str>` or `std::borrow::Cow<'a, [u8]>`.
+pub fn is_cow(ty: &syn::Type) -> bool {
+    named(ty, "Cow")
+}
+
+// Returns whether the type is exactly a `String`, e.g. `String` or `std::string::String`.
+pub fn is_string(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+        return path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String" && segment.arguments.is_empty());
+    }
+
+    false
+}
+
+// Returns whether the type is a `PhantomData`, e.g. `PhantomData<T>` or `std::marker::PhantomData<fn() -> T>`.
+pub fn is_phantom_data(ty: &syn::Type) -> bool {
+    named(ty, "PhantomData")
+}
+
+// Returns whether the values of the type surely don't need to be dropped, which is required to overwrite them in a `const fn`.
+// It holds for primitives, references, pointers, and `PhantomData`, and for arrays, tuples, and `Option`s of them.
+// Other types, including the generic parameters, may implement `Drop` or own a type that does.
+pub fn has_no_drop_glue(ty: &syn::Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f32", "f64",
+    ];
+
+    match ty {
+        syn::Type::Reference(_)
+        | syn::Type::Ptr(_)
+        | syn::Type::BareFn(_)
+        | syn::Type::Never(_) => true,
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Array(syn::TypeArray { elem, .. }) => has_no_drop_glue(elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().all(has_no_drop_glue),
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            if let Some(inner_ty) = is_option(ty) {
+                return has_no_drop_glue(inner_ty);
+            }
+
+            is_phantom_data(ty)
+                || path
+                    .get_ident()
+                    .is_some_and(|ident| PRIMITIVES.iter().any(|primitive| ident == primitive))
+        }
+        _ => false,
+    }
+}
+
+// Returns inner type of a `Box`, `Rc`, or `Arc` and `None` if type is not one of these smart pointers.
+// The pointer may be named by its path, e.g. `std::sync::Arc<T>`.
+pub fn is_smart_pointer(ty: &syn::Type) -> Option<&syn::Type> {
+    if !["Box", "Rc", "Arc"]
+        .iter()
+        .any(|pointer| named(ty, pointer))
+    {
+        return None;
+    }
+
+    let syn::Type::Path(syn::TypePath { path, .. }) = ty else {
+        return None;
+    };
+
+    match &path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            syn::GenericArgument::Type(inner_ty) => Some(inner_ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// `Self` in the type of a field refers to the struct, but the same type placed inside the builder
+// would refer to the builder. This function replaces every `Self` in the types of the fields of
+// the struct with the type of the struct itself, e.g. `Option<Box<Self>>` becomes `Option<Box<Foo<T>>>`.
+pub fn resolve_self(ast: &mut syn::DeriveInput) {
+    fn replace(
+        tokens: proc_macro2::TokenStream,
+        s_ty: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        tokens
+            .into_iter()
+            .map(|token| match token {
+                proc_macro2::TokenTree::Ident(ident) if ident == "Self" => s_ty.clone(),
+                proc_macro2::TokenTree::Group(group) => {
+                    let mut replaced =
+                        proc_macro2::Group::new(group.delimiter(), replace(group.stream(), s_ty));
+                    replaced.set_span(group.span());
+
+                    proc_macro2::TokenTree::Group(replaced).into()
+                }
+                token => token.into(),
+            })
+            .collect()
+    }
+
+    let s_ident = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let s_ty = quote! { #s_ident #ty_generics };
+
+    if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &mut ast.data
+    {
+        for field in fields.named.iter_mut() {
+            let tokens = replace(field.ty.to_token_stream(), &s_ty);
+
+            if let Ok(ty) = syn::parse2(tokens) {
+                field.ty = ty;
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct<F> {
+    req1: usize,
+    req2: F,
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime)]
+struct Runtime {
+    req1: usize,
+    opt1: Option<usize>,
+}
+
+#[test]
+fn branch_in_a_chain() {
+    for verbose in [false, true] {
+        let my_struct = MyStruct::builder()
+            .req2("req2")
+            .apply(|b| if verbose { b.opt1(1) } else { b })
+            .req1(1)
+            .build();
+
+        assert_eq!(my_struct.opt1, verbose.then_some(1));
+    }
+}
+
+#[test]
+fn return_another_state() {
+    // The closure can set required fields, so it moves the builder to another state.
+    let set_required = |b: MyStructBuilder<false, false, _>| b.req1(1).req2(2.0);
+
+    let my_struct = MyStruct::builder().def1(4).apply(set_required).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.def1, 4);
+}
+
+#[test]
+fn runtime_mode() {
+    let built = Runtime::builder()
+        .apply(|b| b.req1(1))
+        .apply(|b| b.try_build());
+
+    assert_eq!(
+        built,
+        Ok(Runtime {
+            req1: 1,
+            opt1: None
+        })
+    );
+}
+
+// The setter of the field takes precedence over `apply`.
+#[derive(tidy_builder::Builder)]
+struct Patch {
+    apply: bool,
+}
+
+#[test]
+fn field_named_apply() {
+    assert!(Patch::builder().apply(true).build().apply);
+}
@@ -0,0 +1,61 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct<T> {
+    req1: T,
+
+    #[builder(each = "arg")]
+    args: Vec<String>,
+
+    opt1: Option<usize>,
+
+    #[builder(each = "tag")]
+    tags: Option<Vec<String>>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s| s.def1 * 2)]
+    def2: usize,
+}
+
+#[test]
+fn consuming_builder() {
+    let my_struct = MyStruct::builder()
+        .req1("req1")
+        .arg("a".to_string())
+        .tag("t".to_string())
+        .build();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.args, vec!["a".to_string()]);
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.tags, Some(vec!["t".to_string()]));
+    assert_eq!(my_struct.def1, 3);
+    assert_eq!(my_struct.def2, 6);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = MyStruct::builder_mut();
+    builder.req1("req1").opt1(1);
+
+    for arg in ["a", "b"] {
+        builder.arg(arg.to_string());
+    }
+    builder.args_extend(vec!["c".to_string()]);
+    builder.def1(4);
+
+    let my_struct = builder.build().unwrap();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.args, vec!["a", "b", "c"]);
+    assert_eq!(my_struct.opt1, Some(1));
+    assert_eq!(my_struct.tags, None);
+    assert_eq!(my_struct.def1, 4);
+    assert_eq!(my_struct.def2, 8);
+
+    let mut builder = MyStruct::<usize>::builder_mut();
+    builder.opt1(1);
+
+    assert_eq!(builder.build(), Err("missing `req1`"));
+}
@@ -0,0 +1,24 @@
+#[allow(clippy::type_complexity)]
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: Box<dyn Fn(u32) -> u32 + Send + Sync>,
+    opt1: Option<Box<dyn Fn(u32) -> u32 + Send + Sync>>,
+
+    #[builder(each = "callback")]
+    callbacks: Vec<Box<dyn Fn(u32) -> u32 + Send + Sync>>,
+}
+
+#[test]
+fn boxed_closures() {
+    let offset = 10;
+    let my_struct = MyStruct::builder()
+        .req1(Box::new(move |x| x + offset))
+        .opt1(Box::new(|x| x * 2))
+        .callback(Box::new(|x| x - 1))
+        .build();
+
+    assert_eq!((my_struct.req1)(1), 11);
+    assert_eq!(my_struct.opt1.map(|f| f(2)), Some(4));
+    assert_eq!(my_struct.callbacks.len(), 1);
+    assert_eq!((my_struct.callbacks[0])(3), 2);
+}
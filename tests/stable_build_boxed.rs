@@ -0,0 +1,40 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+    opt1: Option<String>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default)]
+    buf: [u8; 32],
+}
+
+#[test]
+fn build_boxed() {
+    let my_struct: Box<MyStruct> = MyStruct::builder()
+        .req1(1)
+        .opt1("opt1".to_string())
+        .build_boxed();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.opt1, Some("opt1".to_string()));
+    assert_eq!(my_struct.def1, 3);
+    assert_eq!(my_struct.buf, [0; 32]);
+}
+
+#[derive(tidy_builder::Builder)]
+struct LargeStruct {
+    id: usize,
+
+    #[builder(default)]
+    pages: [u64; 8192],
+}
+
+#[test]
+fn build_boxed_large() {
+    let large: Box<LargeStruct> = LargeStruct::builder().id(1).build_boxed();
+
+    assert_eq!(large.id, 1);
+    assert!(large.pages.iter().all(|page| *page == 0));
+}
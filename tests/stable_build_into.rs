@@ -0,0 +1,99 @@
+use std::mem::MaybeUninit;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct<T> {
+    req1: T,
+
+    #[builder(per_index)]
+    coords: [usize; 2],
+
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s: &MyStruct<T>| s.def1 * 2)]
+    def2: usize,
+}
+
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(repair = |mut s: Range| { if s.start > s.end { std::mem::swap(&mut s.start, &mut s.end); } s })]
+struct Range {
+    start: usize,
+    end: usize,
+}
+
+#[test]
+fn build_into_slot() {
+    let mut slot = MaybeUninit::uninit();
+
+    let my_struct = MyStruct::builder()
+        .req1("req1")
+        .coords_0(1)
+        .coords_1(2)
+        .build_into(&mut slot);
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.coords, [1, 2]);
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.def2, 6);
+
+    my_struct.opt1 = Some(1);
+
+    // The slot owns the struct from now on.
+    let my_struct = unsafe { slot.assume_init() };
+    assert_eq!(my_struct.opt1, Some(1));
+}
+
+#[test]
+fn build_into_slots_of_a_pool() {
+    let mut pool: Vec<MaybeUninit<MyStruct<String>>> =
+        (0..2).map(|_| MaybeUninit::uninit()).collect();
+
+    for (index, slot) in pool.iter_mut().enumerate() {
+        MyStruct::builder()
+            .req1(index.to_string())
+            .coords_0(index)
+            .coords_1(index)
+            .def1(index)
+            .build_into(slot);
+    }
+
+    let pool: Vec<_> = pool
+        .into_iter()
+        .map(|slot| unsafe { slot.assume_init() })
+        .collect();
+
+    assert_eq!(pool[1].req1, "1");
+    assert_eq!(pool[1].def2, 2);
+}
+
+#[test]
+fn build_into_with_repair() {
+    let mut slot = MaybeUninit::uninit();
+
+    let range = Range::builder().start(5).end(1).build_into(&mut slot);
+
+    assert_eq!((range.start, range.end), (1, 5));
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+struct Uninit {
+    req1: String,
+    opt1: Option<String>,
+}
+
+#[test]
+fn build_into_with_maybe_uninit_storage() {
+    let mut slot = MaybeUninit::uninit();
+
+    let uninit = Uninit::builder()
+        .req1("req1".to_string())
+        .build_into(&mut slot);
+
+    assert_eq!(uninit.req1, "req1");
+    assert_eq!(uninit.opt1, None);
+
+    unsafe { slot.assume_init_drop() };
+}
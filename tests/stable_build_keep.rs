@@ -0,0 +1,90 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct<'a, T> {
+    req1: &'a str,
+    req2: T,
+
+    #[builder(per_index)]
+    coords: [f64; 2],
+
+    #[builder(required)]
+    limit: Option<usize>,
+
+    opt1: Option<String>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s: &MyStruct<'a, T>| s.def1 * 2)]
+    def2: usize,
+}
+
+#[test]
+fn build_variants() {
+    let builder = MyStruct::builder()
+        .req1("req1")
+        .req2(vec![1])
+        .coords_0(1.0)
+        .coords_1(2.0)
+        .limit(4)
+        .opt1("opt1".to_string());
+
+    let first = builder.build_keep();
+    let second = builder.def1(4).build_keep();
+
+    assert_eq!(first.req2, [1]);
+    assert_eq!(first.coords, [1.0, 2.0]);
+    assert_eq!(first.limit, Some(4));
+    assert_eq!(first.opt1.as_deref(), Some("opt1"));
+    assert_eq!((first.def1, first.def2), (3, 6));
+    assert_eq!((second.def1, second.def2), (4, 8));
+}
+
+#[test]
+fn same_as_build() {
+    let builder = MyStruct::builder()
+        .req1("req1")
+        .req2(2)
+        .coords_0(1.0)
+        .coords_1(2.0)
+        .limit(4)
+        .def2(5);
+
+    assert_eq!(builder.build_keep(), builder.build());
+}
+
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+#[builder(repair = |mut s: Range| { if s.start > s.end { std::mem::swap(&mut s.start, &mut s.end); } s })]
+struct Range {
+    start: usize,
+    end: usize,
+}
+
+#[test]
+fn maybe_uninit_storage_with_repair() {
+    let builder = Range::builder().start(5).end(1);
+    let range = builder.build_keep();
+
+    assert_eq!((range.start, range.end), (1, 5));
+
+    let range = builder.end(9).build();
+    assert_eq!((range.start, range.end), (5, 9));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, tidy_builder::Builder)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: Option<i32>,
+}
+
+#[test]
+fn copy_fields() {
+    let builder = Point::builder().x(1).y(2);
+
+    // The builder is only borrowed, so it can build the struct as many times as needed.
+    let points: Vec<Point> = (0..3).map(|_| builder.build_keep()).collect();
+
+    assert!(points.iter().all(|&point| point == builder.build_keep()));
+    assert_eq!(builder.z(3).build_keep().z, Some(3));
+}
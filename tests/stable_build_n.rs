@@ -0,0 +1,27 @@
+#[derive(Clone, Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+    opt1: Option<String>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn build_n() {
+    let my_structs = MyStruct::builder()
+        .req1(1)
+        .opt1("opt1".to_string())
+        .build_n(3);
+
+    assert_eq!(my_structs.len(), 3);
+    for my_struct in &my_structs {
+        assert_eq!(my_struct, &my_structs[0]);
+    }
+
+    assert_eq!(my_structs[0].req1, 1);
+    assert_eq!(my_structs[0].opt1, Some("opt1".to_string()));
+    assert_eq!(my_structs[0].def1, 3);
+
+    assert!(MyStruct::builder().req1(1).build_n(0).is_empty());
+}
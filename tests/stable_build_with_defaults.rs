@@ -0,0 +1,41 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+    req2: String,
+
+    #[builder(per_index)]
+    coords: [i32; 2],
+
+    opt1: Option<String>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+struct Uninit {
+    items: Vec<usize>,
+    name: String,
+}
+
+#[test]
+fn build_with_defaults() {
+    let my_struct = MyStruct::builder()
+        .req2("req2".to_string())
+        .coords_1(2)
+        .build_with_defaults();
+
+    assert_eq!(my_struct.req1, 0);
+    assert_eq!(my_struct.req2, "req2");
+    assert_eq!(my_struct.coords, [0, 2]);
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.def1, 3);
+
+    let uninit = Uninit::builder()
+        .name("name".to_string())
+        .build_with_defaults();
+
+    assert!(uninit.items.is_empty());
+    assert_eq!(uninit.name, "name");
+}
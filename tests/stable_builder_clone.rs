@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+#[derive(tidy_builder::Builder)]
+#[builder(derive(Clone))]
+struct MyStruct<'a, T>
+where
+    T: Display,
+{
+    req1: T,
+    req2: &'a str,
+    opt1: Option<String>,
+
+    #[builder(default = 1)]
+    def1: usize,
+}
+
+#[test]
+fn builder_clone() {
+    let base = MyStruct::builder().req1(1).opt1("opt1".to_string());
+
+    let my_struct1 = base.clone().req2("first").build();
+    let my_struct2 = base.def1(2).req2("second").build();
+
+    assert_eq!(my_struct1.req1, 1);
+    assert_eq!(my_struct1.req2, "first");
+    assert_eq!(my_struct1.opt1, Some("opt1".to_string()));
+    assert_eq!(my_struct1.def1, 1);
+
+    assert_eq!(my_struct2.req1, 1);
+    assert_eq!(my_struct2.req2, "second");
+    assert_eq!(my_struct2.opt1, Some("opt1".to_string()));
+    assert_eq!(my_struct2.def1, 2);
+}
@@ -0,0 +1,34 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct<'a, T: Clone> {
+    req1: &'a str,
+    req2: T,
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn builder_default() {
+    let my_struct = MyStructBuilder::default().req1("req1").req2(2).build();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.def1, 3);
+}
+
+fn fresh<B: Default>() -> B {
+    B::default()
+}
+
+#[test]
+fn builder_default_generic() {
+    let builder: MyStructBuilder<false, false, usize> = fresh();
+    let my_struct = builder.req1("req1").req2(2).opt1(1).build();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.opt1, Some(1));
+    assert_eq!(my_struct.def1, 3);
+}
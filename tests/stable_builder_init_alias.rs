@@ -0,0 +1,50 @@
+mod config {
+    #[derive(Debug, PartialEq, tidy_builder::Builder)]
+    pub struct Config<'a, T: Clone, const N: usize> {
+        pub name: &'a str,
+        pub values: [T; N],
+        pub port: Option<u16>,
+    }
+}
+
+use config::{Config, ConfigBuilderComplete, ConfigBuilderInit};
+
+// A fresh builder can be stored without naming its const generic parameters.
+// Like the builder, the alias takes the const generic parameters of the struct before its type parameters.
+struct Factory<'a> {
+    builder: ConfigBuilderInit<'a, 2, u8>,
+}
+
+impl<'a> Factory<'a> {
+    fn new() -> Self {
+        Self {
+            builder: Config::builder(),
+        }
+    }
+}
+
+#[derive(tidy_builder::Builder)]
+struct Plain {
+    req1: usize,
+}
+
+#[test]
+fn store_a_fresh_builder() {
+    let factory = Factory::new();
+    let config = factory
+        .builder
+        .name("app")
+        .values([1, 2])
+        .port(8080)
+        .build();
+
+    assert_eq!(config.values, [1, 2]);
+    assert_eq!(config.port, Some(8080));
+}
+
+#[test]
+fn without_generics() {
+    let builder: PlainBuilderInit = Plain::builder();
+
+    assert_eq!(builder.req1(1).build().req1, 1);
+}
@@ -0,0 +1,40 @@
+#[derive(tidy_builder::Builder)]
+#[builder(derive(Clone))]
+struct MyStruct {
+    /// Other attributes of the fields are left alone.
+    req1: usize,
+
+    #[cfg(not(test))]
+    req2: usize,
+
+    #[cfg(test)]
+    req3: usize,
+
+    #[cfg(not(test))]
+    opt1: Option<usize>,
+
+    #[cfg(test)]
+    opt2: Option<usize>,
+
+    #[cfg(not(test))]
+    #[builder(default = 1)]
+    def1: usize,
+
+    #[cfg(test)]
+    #[builder(default = 2)]
+    def2: usize,
+
+    #[cfg(not(test))]
+    #[builder(each = "arg")]
+    args: Vec<usize>,
+}
+
+#[test]
+fn cfg_fields() {
+    let my_struct = MyStruct::builder().req1(1).req3(3).opt2(2).clone().build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.req3, 3);
+    assert_eq!(my_struct.opt2, Some(2));
+    assert_eq!(my_struct.def2, 2);
+}
@@ -0,0 +1,119 @@
+#[derive(Debug, PartialEq)]
+enum PortError {
+    Zero,
+    Reserved(u16),
+}
+
+impl std::fmt::Display for PortError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortError::Zero => write!(f, "port is zero"),
+            PortError::Reserved(port) => write!(f, "port {} is reserved", port),
+        }
+    }
+}
+
+fn check_port(port: &u16) -> Result<(), PortError> {
+    match *port {
+        0 => Err(PortError::Zero),
+        port if port < 1024 => Err(PortError::Reserved(port)),
+        _ => Ok(()),
+    }
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Server {
+    #[builder(check = check_port, check_error = "PortError")]
+    port: u16,
+
+    host: String,
+
+    #[builder(into, check = |name: &String| if name.is_empty() { Err("empty name") } else { Ok(()) })]
+    name: Option<String>,
+
+    #[builder(default = 4, check = |workers| if *workers == 0 { Err("no workers") } else { Ok(()) })]
+    workers: usize,
+}
+
+#[test]
+fn check() {
+    let server = Server::builder()
+        .port(8080)
+        .unwrap()
+        .host("localhost".to_string())
+        .name("main")
+        .unwrap()
+        .workers(2)
+        .unwrap()
+        .build();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.name, Some("main".to_string()));
+    assert_eq!(server.workers, 2);
+}
+
+#[test]
+fn check_failure() {
+    match Server::builder().port(80) {
+        Err(ServerBuilderError::Port(error)) => assert_eq!(error, PortError::Reserved(80)),
+        _ => panic!("expected a port error"),
+    }
+
+    match Server::builder().name_str("") {
+        Err(ServerBuilderError::Name(error)) => assert_eq!(error, "empty name"),
+        _ => panic!("expected a name error"),
+    }
+
+    let error = Server::builder().workers(0).err().unwrap();
+
+    assert_eq!(error.to_string(), "invalid `workers`: no workers");
+    assert_eq!(format!("{:?}", error), r#"Workers("no workers")"#);
+
+    let error: Box<dyn std::error::Error> = Server::builder().port(0).err().unwrap().into();
+
+    assert_eq!(error.to_string(), "invalid `port`: port is zero");
+}
+
+#[test]
+fn check_mut() {
+    let mut builder = Server::builder_mut();
+
+    assert!(builder.port(0).is_err());
+    builder.port(8080).unwrap().host("localhost".to_string());
+
+    let server = builder.build().unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, 4);
+}
+
+// The checked setters return the builder in the next state inside `Ok`, so `?` chains them.
+fn try_server(port: u16, workers: usize) -> Result<Server, ServerBuilderError> {
+    let server = Server::builder()
+        .port(port)?
+        .host("localhost".to_string())
+        .name("main")?
+        .workers(workers)?
+        .build();
+
+    Ok(server)
+}
+
+#[test]
+fn question_mark_chain() {
+    let server = try_server(8080, 2).unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, 2);
+
+    assert!(matches!(
+        try_server(8080, 0),
+        Err(ServerBuilderError::Workers("no workers"))
+    ));
+    assert!(matches!(
+        try_server(22, 2),
+        Err(ServerBuilderError::Port(PortError::Reserved(22)))
+    ));
+}
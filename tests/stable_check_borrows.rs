@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+// Neither `Copy` nor `Clone`, so the check must not take it by value.
+#[derive(Debug, PartialEq)]
+struct Token(String);
+
+// The check takes a reference to the type of the field.
+#[allow(clippy::ptr_arg)]
+fn check_name(name: &String) -> Result<(), &'static str> {
+    if name.is_empty() {
+        Err("empty name")
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(tidy_builder::Builder)]
+struct User {
+    #[builder(check = check_name)]
+    name: String,
+
+    #[builder(check = |token: &Token| if token.0.len() < 4 { Err("short token") } else { Ok(()) })]
+    token: Token,
+
+    #[builder(setter = wrap, check = |bio: &Arc<String>| if bio.len() > 8 { Err("long bio") } else { Ok(()) })]
+    bio: Option<Arc<String>>,
+}
+
+#[test]
+fn check_borrows() {
+    let user = User::builder()
+        .name("foo".to_string())
+        .unwrap()
+        .token(Token("abcd".to_string()))
+        .unwrap()
+        .bio("bar".to_string())
+        .unwrap()
+        .build();
+
+    assert_eq!(user.name, "foo");
+    assert_eq!(user.token, Token("abcd".to_string()));
+    assert_eq!(user.bio.as_deref().map(String::as_str), Some("bar"));
+
+    assert!(User::builder().name(String::new()).is_err());
+    assert!(User::builder().token(Token("abc".to_string())).is_err());
+    assert!(User::builder().bio("too long bio".to_string()).is_err());
+}
@@ -0,0 +1,53 @@
+const MAX_RETRIES: usize = 5;
+
+#[derive(tidy_builder::Builder)]
+struct Job {
+    // Captures a constant.
+    #[builder(check = move |retries: &usize| if *retries > MAX_RETRIES { Err("too many retries") } else { Ok(()) })]
+    retries: usize,
+
+    // Captures a local of the block which builds the closure.
+    #[builder(check = {
+        let threshold = 10;
+        move |priority: &u8| if *priority > threshold { Err("priority too high") } else { Ok(()) }
+    })]
+    priority: u8,
+
+    // Mutates its captured state, so it's only `FnMut`.
+    #[builder(check = {
+        let mut seen = 0;
+        move |_: &String| {
+            seen += 1;
+            if seen > 1 { Err("called twice") } else { Ok(()) }
+        }
+    })]
+    name: Option<String>,
+}
+
+#[test]
+fn check_captures() {
+    let job = Job::builder()
+        .retries(3)
+        .unwrap()
+        .priority(10)
+        .unwrap()
+        .name("backup".to_string())
+        .unwrap()
+        .name("restore".to_string())
+        .unwrap()
+        .build();
+
+    assert_eq!(job.retries, 3);
+    assert_eq!(job.priority, 10);
+    // The closure is created on every call of the setter, so its state doesn't carry over.
+    assert_eq!(job.name, Some("restore".to_string()));
+
+    assert!(matches!(
+        Job::builder().retries(6),
+        Err(JobBuilderError::Retries("too many retries"))
+    ));
+    assert!(matches!(
+        Job::builder().priority(11),
+        Err(JobBuilderError::Priority("priority too high"))
+    ));
+}
@@ -0,0 +1,73 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Range {
+    min: u32,
+
+    #[builder(check_with = |builder, max: &u32| match builder.min {
+        Some(min) if min > *max => Err("below `min`"),
+        _ => Ok(()),
+    })]
+    max: u32,
+
+    #[builder(default = 1, check_with = |builder: &Self, step: &u32| match (builder.min, builder.max) {
+        (Some(min), Some(max)) if *step > max.saturating_sub(min) => Err("larger than the range"),
+        _ => Ok(()),
+    })]
+    step: u32,
+}
+
+#[test]
+fn compare_to_fields_set_before() {
+    let range = Range::builder()
+        .min(1)
+        .max(10)
+        .unwrap()
+        .step(3)
+        .unwrap()
+        .build();
+
+    assert_eq!(range.step, 3);
+
+    match Range::builder().min(10).max(1) {
+        Err(RangeBuilderError::Max(error)) => assert_eq!(error, "below `min`"),
+        _ => unreachable!(),
+    }
+
+    match Range::builder().min(1).max(2).unwrap().step(5) {
+        Err(RangeBuilderError::Step(error)) => assert_eq!(error, "larger than the range"),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn fields_set_after_are_not_seen() {
+    let range = Range::builder().max(1).unwrap().min(10).build();
+
+    assert_eq!(range.min, 10);
+    assert_eq!(range.max, 1);
+}
+
+#[test]
+fn validate() {
+    let builder = Range::builder().max(1).unwrap().min(10);
+
+    let errors = builder.validate().unwrap_err();
+
+    assert!(matches!(
+        errors[..],
+        [
+            RangeBuilderError::Max("below `min`"),
+            RangeBuilderError::Step("larger than the range"),
+        ]
+    ));
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Range::builder_mut();
+    builder.min(10);
+
+    assert!(matches!(builder.max(1), Err(RangeBuilderError::Max(_))));
+    assert!(builder.max(20).is_ok());
+    assert_eq!(builder.build().unwrap().max, 20);
+}
@@ -0,0 +1,32 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+pub struct MyStruct<T> {
+    req1: T,
+    req2: usize,
+    opt1: Option<usize>,
+}
+
+fn finish<T>(builder: impl MyStructBuilderComplete<Output = MyStruct<T>>) -> MyStruct<T> {
+    builder.build()
+}
+
+// Only complete builders can be passed, whatever the values of their const generic parameters are.
+fn finish_any(builder: impl MyStructBuilderComplete) -> bool {
+    let _built = builder.build();
+
+    true
+}
+
+#[test]
+fn complete_trait() {
+    let my_struct = finish(MyStruct::builder().req1("req1").req2(2).opt1(3));
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.opt1, Some(3));
+}
+
+#[test]
+fn accepts_any_complete_builder() {
+    assert!(finish_any(MyStruct::builder().req1(1).req2(2)));
+    assert!(finish_any(MyStruct::builder().req2(2).opt1(3).req1("req1")));
+}
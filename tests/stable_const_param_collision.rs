@@ -0,0 +1,22 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct<const P0: usize, P1: Default, _P0> {
+    req1: [u8; P0],
+    req2: P1,
+    req3: _P0,
+    opt1: Option<usize>,
+}
+
+#[test]
+fn const_param_collision() {
+    let my_struct = MyStruct::builder()
+        .req1([1, 2])
+        .req2(2)
+        .req3("req3")
+        .opt1(1)
+        .build();
+
+    assert_eq!(my_struct.req1, [1, 2]);
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.req3, "req3");
+    assert_eq!(my_struct.opt1, Some(1));
+}
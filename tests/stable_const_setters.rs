@@ -0,0 +1,91 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(const)]
+struct Config {
+    host: &'static str,
+    port: u16,
+
+    #[builder(once)]
+    workers: Option<usize>,
+
+    verbose: Option<bool>,
+
+    #[builder(default = 3)]
+    retries: u8,
+}
+
+// A `const fn` can call the setters that only assign their argument.
+const fn local(builder: ConfigBuilder<false, false, false>) -> ConfigBuilder<true, true, true> {
+    builder
+        .host("localhost")
+        .port(8080)
+        .workers(4)
+        .verbose(true)
+        .retries(5)
+}
+
+#[test]
+fn const_setters() {
+    let config = local(Config::builder()).build();
+
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost",
+            port: 8080,
+            workers: Some(4),
+            verbose: Some(true),
+            retries: 5,
+        }
+    );
+}
+
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(const, setters_trait)]
+struct Limits {
+    max: Option<u32>,
+}
+
+#[test]
+fn setters_trait() {
+    const fn max(builder: LimitsBuilder) -> LimitsBuilder {
+        builder.max(10)
+    }
+
+    fn max_through_trait<B: LimitsSetters>(builder: B) -> B {
+        builder.max(20)
+    }
+
+    assert_eq!(max(Limits::builder()).build().max, Some(10));
+    assert_eq!(max_through_trait(Limits::builder()).build().max, Some(20));
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(const)]
+struct Labeled {
+    id: u32,
+    label: String,
+    point: Option<(i32, i32)>,
+}
+
+// Only the setters of values without drop glue are `const`. So are the setters of required fields only when
+// no value of the builder has drop glue, since they leave the builder partially moved.
+const fn located(builder: LabeledBuilder<false, false>) -> LabeledBuilder<false, false> {
+    builder.point((2, 3))
+}
+
+#[test]
+fn const_setters_skip_values_with_drop_glue() {
+    let labeled = located(Labeled::builder())
+        .id(1)
+        .label("origin".to_string())
+        .build();
+
+    assert_eq!(
+        labeled,
+        Labeled {
+            id: 1,
+            label: "origin".to_string(),
+            point: Some((2, 3)),
+        }
+    );
+}
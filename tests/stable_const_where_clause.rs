@@ -0,0 +1,36 @@
+trait Sized2<const N: usize> {}
+
+impl<const N: usize> Sized2<N> for u8 {}
+
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes, derive(Clone), into_builder)]
+struct MyStruct<const N: usize, T>
+where
+    [(); N]: Sized,
+    T: Sized2<N> + Clone,
+{
+    req1: [T; N],
+    req2: T,
+    opt1: Option<T>,
+
+    #[builder(default)]
+    def1: usize,
+}
+
+#[test]
+fn const_where_clause() {
+    let my_struct = MyStruct::builder().req1([1u8, 2]).req2(3).build();
+
+    assert_eq!(my_struct.req1, [1, 2]);
+    assert_eq!(my_struct.req2, 3);
+    assert_eq!(my_struct.opt1, None);
+
+    let my_struct = MyStructBuilder::from(my_struct).clone().opt1(4).build();
+
+    assert_eq!(my_struct.opt1, Some(4));
+
+    let mut builder = MyStruct::<2, u8>::builder_mut();
+    builder.req1([5, 6]).req2(7);
+
+    assert_eq!(builder.build().unwrap().req1, [5, 6]);
+}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Config<'a> {
+    name: Cow<'a, str>,
+    description: Option<Cow<'a, str>>,
+
+    #[builder(default)]
+    payload: std::borrow::Cow<'a, [u8]>,
+
+    #[builder(default = Cow::Borrowed("id"), no_into)]
+    id: Cow<'a, str>,
+}
+
+#[test]
+fn borrowed_or_owned() {
+    let owned = String::from("owned");
+
+    let config = Config::builder()
+        .name("borrowed")
+        .description(owned)
+        .payload(vec![1, 2])
+        .build();
+
+    assert_eq!(config.name, "borrowed");
+    assert!(matches!(config.name, Cow::Borrowed(_)));
+    assert_eq!(config.description.as_deref(), Some("owned"));
+    assert!(matches!(config.description, Some(Cow::Owned(_))));
+    assert_eq!(&*config.payload, &[1, 2]);
+
+    let bytes = [3, 4];
+    let config = Config::builder()
+        .name(Cow::Borrowed("cow"))
+        .payload(&bytes[..])
+        .build();
+
+    assert_eq!(config.name, "cow");
+    assert_eq!(&*config.payload, &[3, 4]);
+}
+
+#[test]
+fn no_into() {
+    let config = Config::builder()
+        .name("name")
+        .id(Cow::Owned("id".to_string()))
+        .build();
+
+    assert_eq!(config.id, "id");
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Config::builder_mut();
+    builder.name("name").description("description");
+
+    let config = builder.build().unwrap();
+    assert_eq!(config.description.as_deref(), Some("description"));
+}
@@ -0,0 +1,27 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(default)]
+    def1: [u8; 32],
+
+    #[builder(default)]
+    def2: [u8; 64],
+
+    #[builder(default)]
+    def3: [String; 40],
+
+    #[builder(default, once_cell)]
+    def4: [usize; 100],
+
+    req1: usize,
+}
+
+#[test]
+fn default_values_arrays() {
+    let my_struct = MyStruct::builder().req1(1).build();
+
+    assert_eq!(my_struct.def1, [0; 32]);
+    assert_eq!(my_struct.def2, [0; 64]);
+    assert!(my_struct.def3.iter().all(String::is_empty));
+    assert_eq!(my_struct.def4, [0; 100]);
+    assert_eq!(my_struct.req1, 1);
+}
@@ -0,0 +1,33 @@
+const SIZE: usize = 4;
+
+fn offset() -> usize {
+    1
+}
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(default = 2 * SIZE + offset())]
+    def1: usize,
+
+    #[builder(default = -1)]
+    def2: i32,
+
+    #[builder(default = vec!["a".to_string(), "b".to_string()])]
+    def3: Vec<String>,
+
+    #[builder(default = "name".to_string(), once_cell)]
+    def4: String,
+
+    req1: usize,
+}
+
+#[test]
+fn default_values_expr() {
+    let my_struct = MyStruct::builder().req1(1).build();
+
+    assert_eq!(my_struct.def1, 9);
+    assert_eq!(my_struct.def2, -1);
+    assert_eq!(my_struct.def3, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(my_struct.def4, "name");
+    assert_eq!(my_struct.req1, 1);
+}
@@ -0,0 +1,33 @@
+fn perimeter(rect: &Rect) -> usize {
+    2 * (rect.width + rect.height)
+}
+
+#[derive(tidy_builder::Builder)]
+struct Rect {
+    width: usize,
+    height: usize,
+
+    #[builder(default_from = |rect| rect.width * rect.height)]
+    area: usize,
+
+    #[builder(default_from = |rect| format!("{}x{} ({})", rect.width, rect.height, rect.area))]
+    label: String,
+
+    #[builder(default_from = perimeter)]
+    perimeter: usize,
+}
+
+#[test]
+fn default_values_from_fields() {
+    let rect = Rect::builder().width(2).height(3).build();
+
+    assert_eq!(rect.area, 6);
+    assert_eq!(rect.label, "2x3 (6)");
+    assert_eq!(rect.perimeter, 10);
+
+    let rect = Rect::builder().width(2).height(3).area(1).build();
+
+    assert_eq!(rect.area, 1);
+    assert_eq!(rect.label, "2x3 (1)");
+    assert_eq!(rect.perimeter, 10);
+}
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn expensive_names() -> Vec<String> {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+
+    vec!["name1".to_string(), "name2".to_string()]
+}
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(default = expensive_names, once_cell)]
+    names: Vec<String>,
+
+    #[builder(default, once_cell)]
+    count: usize,
+
+    req1: usize,
+}
+
+#[test]
+fn default_values_once_cell() {
+    let my_struct1 = MyStruct::builder().req1(1).build();
+    let my_struct2 = MyStruct::builder().req1(2).build();
+    let my_struct3 = MyStruct::builder().req1(3).names(vec![]).build();
+
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    assert_eq!(
+        my_struct1.names,
+        vec!["name1".to_string(), "name2".to_string()]
+    );
+    assert_eq!(my_struct2.names, my_struct1.names);
+    assert!(my_struct3.names.is_empty());
+
+    assert_eq!(my_struct1.count, 0);
+    assert_eq!(my_struct3.req1, 3);
+}
@@ -0,0 +1,32 @@
+#[derive(tidy_builder::Builder)]
+#[builder(deprecated = "use MyStruct::new instead")]
+struct MyStruct {
+    req1: usize,
+    opt1: Option<usize>,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(deprecated = "use Config::new instead")]
+struct Config {
+    #[builder(default = 3)]
+    retries: usize,
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_builder() {
+    let my_struct = MyStruct::builder().req1(1).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.opt1, None);
+}
+
+#[test]
+fn deprecated_builder_default() {
+    // The generated `Default` impls don't trigger the deprecation.
+    let builder = MyStructBuilder::default();
+    let config = Config::default();
+
+    assert_eq!(builder.req1(2).build().req1, 2);
+    assert_eq!(config.retries, 3);
+}
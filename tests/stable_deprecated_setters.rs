@@ -0,0 +1,45 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    #[builder(deprecated = "use req2 instead")]
+    req1: usize,
+
+    req2: usize,
+
+    #[builder(deprecated = "use tags instead", each = "arg")]
+    args: Option<Vec<String>>,
+
+    #[builder(deprecated = "use the default", default = 3)]
+    def1: usize,
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_setters_still_work() {
+    let my_struct = MyStruct::builder()
+        .req1(1)
+        .req2(2)
+        .arg("a".to_string())
+        .args_extend(vec!["b".to_string()])
+        .def1(4)
+        .build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.args, Some(vec!["a".to_string(), "b".to_string()]));
+    assert_eq!(my_struct.def1, 4);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.req1(1).req2(2).set_args(None);
+
+    assert_eq!(builder.build().unwrap().args, None);
+}
+
+#[test]
+#[deny(deprecated)]
+fn other_setters_are_not_deprecated() {
+    // Neither `build` nor the setters of other fields use the deprecated setters.
+    let my_struct = MyStructBuilder::with_required(1, 2).build();
+
+    assert_eq!(my_struct.def1, 3);
+}
@@ -0,0 +1,64 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Node {
+    name: &'static str,
+    weight: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Tree {
+    #[builder(each = "child", each_builder)]
+    children: Vec<Node>,
+
+    #[builder(each = "leaf", each_builder)]
+    leaves: Option<Vec<Node>>,
+
+    #[builder(default, each = "tag", each_builder)]
+    tags: Vec<Node>,
+}
+
+#[test]
+fn build_items_in_place() {
+    let tree = Tree::builder()
+        .child_with(|node| node.name("a"))
+        .child(Node::builder().name("b").build())
+        .child_with(|node| node.name("c").weight(3))
+        .leaf_with(|node| node.name("d"))
+        .tag_with(|node| node.name("e"))
+        .build();
+
+    assert_eq!(tree.children.len(), 3);
+    assert_eq!(tree.children[0].name, "a");
+    assert_eq!(tree.children[2].weight, Some(3));
+    assert_eq!(tree.leaves.unwrap()[0].name, "d");
+    assert_eq!(tree.tags[0].name, "e");
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Tree::builder_mut();
+    for name in ["a", "b"] {
+        builder.child_with(|node| node.name(name));
+    }
+
+    let tree = builder.build().unwrap();
+    assert_eq!(tree.children[1].name, "b");
+    assert_eq!(tree.leaves, None);
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime)]
+struct Runtime {
+    #[builder(each = "node", each_builder)]
+    nodes: Vec<Node>,
+}
+
+#[test]
+fn runtime_mode() {
+    let runtime = Runtime::builder()
+        .node_with(|node| node.name("a"))
+        .try_build()
+        .unwrap();
+
+    assert_eq!(runtime.nodes[0].name, "a");
+}
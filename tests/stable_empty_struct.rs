@@ -0,0 +1,35 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Empty {}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct EmptyBothModes {}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime)]
+struct EmptyRuntime {}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Single {
+    value: usize,
+}
+
+#[test]
+fn empty_struct() {
+    assert_eq!(Empty::builder().build(), Empty {});
+    assert_eq!(Empty::default(), Empty {});
+
+    let builder: EmptyBuilderInit = EmptyBuilder::default();
+    assert_eq!(builder.build(), Empty {});
+
+    assert_eq!(EmptyBothModes::builder_mut().build(), Ok(EmptyBothModes {}));
+    assert_eq!(EmptyRuntime::builder().try_build(), Ok(EmptyRuntime {}));
+    assert_eq!(EmptyRuntime::default(), EmptyRuntime {});
+}
+
+#[test]
+fn single_field() {
+    let builder: SingleBuilderInit = Single::builder();
+
+    assert_eq!(builder.value(1).build(), Single { value: 1 });
+}
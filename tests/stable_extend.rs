@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Command {
+    program: String,
+
+    #[builder(each = "arg")]
+    args: Option<Vec<String>>,
+}
+
+#[test]
+fn extend_from_an_iterator() {
+    let mut builder = Command::builder().program("ls".to_string());
+    builder.extend(["-l".to_string(), "-a".to_string()]);
+
+    let command = builder.arg("src".to_string()).build();
+    assert_eq!(command.args.unwrap(), ["-l", "-a", "src"]);
+}
+
+#[test]
+fn empty_iterator() {
+    let mut builder = Command::builder();
+    builder.extend(None);
+
+    assert_eq!(builder.program("ls".to_string()).build().args, None);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Command::builder_mut();
+    builder.program("ls".to_string());
+    builder.extend(vec!["-l".to_string()]);
+
+    assert_eq!(builder.build().unwrap().args.unwrap(), ["-l"]);
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Env {
+    #[builder(default, each = "var")]
+    vars: BTreeMap<String, String>,
+}
+
+#[test]
+fn default_map() {
+    let mut builder = Env::builder();
+    builder.extend([("HOME".to_string(), "/root".to_string())]);
+
+    let env = builder.build();
+    assert_eq!(env.vars["HOME"], "/root");
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime)]
+struct Runtime {
+    #[builder(each = "item", on_push = |item: &u8| assert!(*item < 10))]
+    items: Vec<u8>,
+}
+
+#[test]
+fn runtime_mode_with_a_required_field() {
+    let mut builder = Runtime::builder();
+    builder.extend(1..4);
+
+    assert_eq!(builder.try_build().unwrap().items, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn on_push_sees_every_item() {
+    Runtime::builder().extend([1, 20]);
+}
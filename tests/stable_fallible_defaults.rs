@@ -0,0 +1,56 @@
+use std::num::ParseIntError;
+
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(try_default = "8080".parse::<u16>())]
+    port: u16,
+}
+
+#[derive(Debug, PartialEq)]
+struct ConfigError(String);
+
+impl From<ParseIntError> for ConfigError {
+    fn from(error: ParseIntError) -> Self {
+        ConfigError(error.to_string())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(tidy_builder::Builder)]
+#[builder(error = "ConfigError")]
+struct Config {
+    #[builder(try_default = "not a number".parse::<u16>())]
+    port: u16,
+
+    #[builder(default = 1)]
+    workers: usize,
+}
+
+#[test]
+fn fallible_defaults() {
+    let my_struct = MyStruct::builder().unwrap().req1(1).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.port, 8080);
+
+    let my_struct = MyStruct::builder().unwrap().req1(1).port(80).build();
+    assert_eq!(my_struct.port, 80);
+
+    let mut builder = MyStruct::builder_mut().unwrap();
+    builder.req1(2);
+
+    assert_eq!(builder.build().unwrap().port, 8080);
+}
+
+#[test]
+fn fallible_defaults_with_error_type() {
+    let error = Config::builder().err().unwrap();
+
+    assert_eq!(
+        error,
+        ConfigError("invalid digit found in string".to_string())
+    );
+}
@@ -0,0 +1,36 @@
+use std::num::ParseIntError;
+
+fn try_compute() -> Result<usize, ParseIntError> {
+    "42".parse()
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(error = "ParseIntError")]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(try_value = try_compute())]
+    computed: usize,
+
+    #[builder(try_default = "1".parse::<usize>())]
+    def1: usize,
+}
+
+#[allow(dead_code)]
+#[derive(tidy_builder::Builder)]
+#[builder(error = "ParseIntError")]
+struct Invalid {
+    #[builder(try_value = "invalid".parse::<usize>())]
+    computed: usize,
+}
+
+#[test]
+fn fallible_values() {
+    let my_struct = MyStruct::builder().unwrap().req1(1).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.computed, 42);
+    assert_eq!(my_struct.def1, 1);
+
+    assert!(Invalid::builder().is_err());
+}
@@ -0,0 +1,21 @@
+// This crate doesn't declare a `builder` feature, which a crate using the attribute would do.
+#![allow(unexpected_cfgs)]
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(feature = "builder")]
+struct MyStruct {
+    req1: usize,
+}
+
+// The builder is only generated when the `builder` feature is enabled.
+// Since it's not, defining another `builder` function doesn't collide with the generated one.
+impl MyStruct {
+    fn builder() -> MyStruct {
+        MyStruct { req1: 0 }
+    }
+}
+
+#[test]
+fn feature_gated() {
+    assert_eq!(MyStruct::builder(), MyStruct { req1: 0 });
+}
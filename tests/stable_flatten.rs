@@ -0,0 +1,56 @@
+mod net {
+    #[derive(Debug, PartialEq, tidy_builder::Builder)]
+    pub struct Address {
+        pub host: String,
+        pub port: u16,
+        pub secure: Option<bool>,
+    }
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Pair<T> {
+    left: T,
+    right: T,
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Server {
+    name: String,
+
+    #[builder(flatten)]
+    address: net::Address,
+
+    #[builder(flatten, name = "range")]
+    pair: Pair<usize>,
+}
+
+#[test]
+fn configure_nested_builders() {
+    let server = Server::builder()
+        .name("app".to_string())
+        .address_with(|b| b.host("localhost".to_string()).port(8080))
+        .range_with(|b| b.right(2).left(1))
+        .build();
+
+    assert_eq!(server.address.host, "localhost");
+    assert_eq!(server.address.port, 8080);
+    assert_eq!(server.address.secure, None);
+    assert_eq!(server.pair, Pair { left: 1, right: 2 });
+}
+
+#[test]
+fn whole_value_setter() {
+    let address = net::Address {
+        host: "localhost".to_string(),
+        port: 8080,
+        secure: Some(true),
+    };
+
+    let server = Server::builder()
+        .address(address)
+        .name("app".to_string())
+        .range(Pair { left: 1, right: 2 })
+        .build();
+
+    assert_eq!(server.address.secure, Some(true));
+}
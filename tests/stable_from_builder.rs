@@ -0,0 +1,19 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct MyStruct<'a, T> {
+    req1: &'a str,
+    req2: T,
+    opt1: Option<usize>,
+}
+
+#[test]
+fn from_builder() {
+    let my_struct: MyStruct<usize> = MyStruct::builder().req1("req1").req2(2).opt1(3).into();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.opt1, Some(3));
+
+    let my_struct = MyStruct::from(MyStruct::builder().req2(2).req1("req1"));
+
+    assert_eq!(my_struct.opt1, None);
+}
@@ -0,0 +1,91 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(from_pairs)]
+struct Config {
+    host: String,
+    user: Option<String>,
+
+    #[builder(default = "fast".to_string())]
+    mode: String,
+
+    #[builder(default_from = |c: &Config| format!("{}@{}", c.user.as_deref().unwrap_or("anonymous"), c.host))]
+    name: String,
+
+    #[builder(skip)]
+    comment: Option<String>,
+}
+
+fn pairs(pairs: &[(&'static str, &str)]) -> Vec<(&'static str, String)> {
+    pairs
+        .iter()
+        .map(|&(name, value)| (name, value.to_string()))
+        .collect()
+}
+
+#[test]
+fn known_fields() {
+    let config = Config::from_pairs(pairs(&[("user", "admin"), ("host", "localhost")]));
+
+    assert_eq!(
+        config,
+        Ok(Config {
+            host: "localhost".to_string(),
+            user: Some("admin".to_string()),
+            mode: "fast".to_string(),
+            name: "admin@localhost".to_string(),
+            comment: None,
+        })
+    );
+
+    let config =
+        Config::from_pairs(pairs(&[("host", "h"), ("mode", "slow"), ("name", "n")])).unwrap();
+
+    assert_eq!((config.mode.as_str(), config.name.as_str()), ("slow", "n"));
+}
+
+#[test]
+fn unknown_fields() {
+    let error = Config::from_pairs(pairs(&[("host", "localhost"), ("port", "80")])).unwrap_err();
+
+    assert_eq!(error, ConfigBuilderPairsError::Unknown("port"));
+    assert_eq!(error.to_string(), "unknown field `port`");
+
+    // Skipped fields can't be set.
+    let error = Config::from_pairs(pairs(&[("comment", "none")])).unwrap_err();
+
+    assert_eq!(error, ConfigBuilderPairsError::Unknown("comment"));
+}
+
+#[test]
+fn missing_fields() {
+    let error = Config::from_pairs(pairs(&[("user", "admin")])).unwrap_err();
+
+    assert_eq!(error, ConfigBuilderPairsError::Missing("host"));
+    assert_eq!(error.to_string(), "missing field `host`");
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(from_pairs, mode = runtime)]
+struct Limits {
+    #[builder(per_index)]
+    range: [u32; 2],
+
+    #[builder(required)]
+    max: Option<u32>,
+}
+
+#[test]
+fn runtime_mode() {
+    let limits = Limits::from_pairs([("range_0", 1), ("range_1", 2), ("max", 3)]);
+
+    assert_eq!(
+        limits,
+        Ok(Limits {
+            range: [1, 2],
+            max: Some(3)
+        })
+    );
+
+    let limits = Limits::from_pairs([("range_0", 1), ("max", 3)]);
+
+    assert_eq!(limits, Err(LimitsBuilderPairsError::Missing("range_1")));
+}
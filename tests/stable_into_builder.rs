@@ -0,0 +1,58 @@
+#[derive(tidy_builder::Builder)]
+#[builder(into_builder)]
+struct MyStruct<'a, T> {
+    req1: &'a str,
+    req2: T,
+
+    #[builder(per_index)]
+    coords: [i32; 2],
+
+    #[builder(each = "item")]
+    items: Vec<usize>,
+
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s: &MyStruct<T>| s.def1 * 2)]
+    def2: usize,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(into_builder, storage = maybe_uninit)]
+struct Uninit {
+    name: String,
+}
+
+#[test]
+fn into_builder() {
+    let my_struct = MyStruct::builder()
+        .req1("req1")
+        .req2(2)
+        .coords_0(1)
+        .coords_1(2)
+        .item(1)
+        .opt1(1)
+        .build();
+
+    let my_struct = MyStructBuilder::from(my_struct)
+        .req1("new")
+        .item(2)
+        .def1(5)
+        .build();
+
+    assert_eq!(my_struct.req1, "new");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.coords, [1, 2]);
+    assert_eq!(my_struct.items, vec![1, 2]);
+    assert_eq!(my_struct.opt1, Some(1));
+    assert_eq!(my_struct.def1, 5);
+    // The computed value is kept since it's known when converting back into the builder.
+    assert_eq!(my_struct.def2, 6);
+
+    let uninit = Uninit::builder().name("name".to_string()).build();
+    let builder: UninitBuilder<true> = uninit.into();
+
+    assert_eq!(builder.build().name, "name");
+}
@@ -0,0 +1,57 @@
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    #[builder(into)]
+    req1: String,
+
+    #[builder(into)]
+    opt1: Option<PathBuf>,
+
+    #[builder(into, default = 1)]
+    def1: u64,
+
+    #[builder(into, setter = wrap)]
+    req2: Rc<String>,
+}
+
+#[test]
+fn into_setters() {
+    let my_struct = MyStruct::builder()
+        .req1("req1")
+        .opt1("/tmp")
+        .def1(2u8)
+        .req2("req2")
+        .build();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.opt1, Some(PathBuf::from("/tmp")));
+    assert_eq!(my_struct.def1, 2);
+    assert_eq!(*my_struct.req2, "req2");
+}
+
+#[test]
+fn borrowed_setters() {
+    let name = String::from("req1");
+    let path = PathBuf::from("/tmp");
+
+    let my_struct = MyStruct::builder()
+        .req1_str(name.as_ref())
+        .opt1_path(path.as_ref())
+        .req2_str(name.as_ref())
+        .build();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.opt1.as_deref(), Some(Path::new("/tmp")));
+    assert_eq!(my_struct.def1, 1);
+    assert_eq!(*my_struct.req2, "req1");
+
+    let mut builder = MyStruct::builder_mut();
+    builder.req1_str(name.as_ref()).req2("req2").def1(3u32);
+    let my_struct = builder.build().unwrap();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.def1, 3);
+}
@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(tidy_builder::Builder)]
+#[builder(into)]
+struct MyStruct {
+    name: String,
+    root: Option<PathBuf>,
+
+    #[builder(per_index)]
+    coords: [f64; 2],
+
+    #[builder(default = 8080, no_into)]
+    port: u16,
+
+    #[builder(to_string)]
+    id: String,
+
+    #[builder(setter = wrap)]
+    shared: Arc<String>,
+}
+
+#[test]
+fn every_setter_takes_into() {
+    let my_struct = MyStruct::builder()
+        .name("app")
+        .root("/srv")
+        .coords_0(1.0_f32)
+        .coords_1(2)
+        .port(80)
+        .id(42)
+        .shared("shared")
+        .build();
+
+    assert_eq!(my_struct.name, "app");
+    assert_eq!(my_struct.root, Some(PathBuf::from("/srv")));
+    assert_eq!(my_struct.coords, [1.0, 2.0]);
+    assert_eq!(my_struct.port, 80);
+    assert_eq!(my_struct.id, "42");
+    assert_eq!(*my_struct.shared, "shared");
+}
+
+#[test]
+fn borrowed_setters() {
+    let name = String::from("app");
+    let my_struct = MyStruct::builder()
+        .name_str(name.as_ref())
+        .coords_0(1.0)
+        .coords_1(2.0)
+        .id("id")
+        .shared_str("shared")
+        .build();
+
+    assert_eq!(my_struct.name, "app");
+    assert_eq!(my_struct.port, 8080);
+}
@@ -0,0 +1,105 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+// Counts how many times it's dropped.
+#[derive(Clone)]
+struct Tracked(Rc<Cell<usize>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit, derive(Clone))]
+struct MyStruct {
+    req1: Tracked,
+    req2: Tracked,
+
+    #[builder(each = "item")]
+    items: Vec<Tracked>,
+
+    opt1: Option<Tracked>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn build() {
+    let drops = Rc::new(Cell::new(0));
+    let tracked = || Tracked(drops.clone());
+
+    let my_struct = MyStruct::builder()
+        .req1(tracked())
+        .req2(tracked())
+        .item(tracked())
+        .item(tracked())
+        .opt1(tracked())
+        .build();
+
+    assert_eq!(drops.get(), 0);
+    assert_eq!(my_struct.items.len(), 2);
+    assert!(my_struct.opt1.is_some());
+    assert_eq!(my_struct.def1, 3);
+
+    drop((my_struct.req1, my_struct.req2));
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn drop_partially_initialized() {
+    let drops = Rc::new(Cell::new(0));
+    let tracked = || Tracked(drops.clone());
+
+    let builder = MyStruct::builder().req1(tracked()).item(tracked());
+    drop(builder);
+
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn overwrite() {
+    let drops = Rc::new(Cell::new(0));
+    let tracked = || Tracked(drops.clone());
+
+    let builder = MyStruct::builder().req1(tracked()).req1(tracked());
+    assert_eq!(drops.get(), 1);
+
+    let clone = builder.clone();
+    drop(builder);
+    assert_eq!(drops.get(), 2);
+
+    let my_struct = clone.req2(tracked()).items(vec![]).build();
+    assert_eq!(drops.get(), 2);
+
+    drop(my_struct);
+    assert_eq!(drops.get(), 4);
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+struct Generic<T> {
+    value: T,
+
+    #[builder(per_index)]
+    coords: [i32; 2],
+}
+
+#[test]
+fn generic() {
+    let generic = Generic::builder()
+        .coords_1(2)
+        .value("value".to_string())
+        .coords_0(1)
+        .build();
+
+    assert_eq!(generic.value, "value");
+    assert_eq!(generic.coords, [1, 2]);
+
+    let generic = GenericBuilder::with_required(1, [3, 4]).build();
+
+    assert_eq!(generic.value, 1);
+    assert_eq!(generic.coords, [3, 4]);
+}
@@ -0,0 +1,21 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    meta: Option<Option<String>>,
+}
+
+#[test]
+fn nested_optional() {
+    let absent = MyStruct::builder().build();
+    let null = MyStruct::builder().meta(None).build();
+    let present = MyStruct::builder().meta(Some("meta".to_string())).build();
+
+    assert_eq!(absent.meta, None);
+    assert_eq!(null.meta, Some(None));
+    assert_eq!(present.meta, Some(Some("meta".to_string())));
+
+    let mut builder = MyStruct::builder_mut();
+    builder.meta(None);
+
+    assert_eq!(builder.build().unwrap().meta, Some(None));
+}
@@ -0,0 +1,33 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+#[derive(Clone, tidy_builder::Builder)]
+#[builder(no_std, derive(Clone))]
+struct MyStruct {
+    req1: usize,
+    opt1: Option<usize>,
+
+    #[builder(default)]
+    def1: usize,
+
+    #[builder(each = "arg")]
+    args: Vec<usize>,
+}
+
+#[test]
+fn no_std() {
+    let my_structs = MyStruct::builder()
+        .req1(1)
+        .opt1(2)
+        .arg(3)
+        .arg(4)
+        .clone()
+        .build_n(2);
+
+    assert_eq!(my_structs.len(), 2);
+    assert_eq!(my_structs[0].req1, 1);
+    assert_eq!(my_structs[0].opt1, Some(2));
+    assert_eq!(my_structs[0].def1, 0);
+    assert_eq!(my_structs[1].args, [3, 4]);
+}
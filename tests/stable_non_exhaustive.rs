@@ -0,0 +1,37 @@
+mod config {
+    #[derive(Debug, PartialEq, tidy_builder::Builder)]
+    #[builder(into_builder)]
+    #[non_exhaustive]
+    pub struct Config {
+        pub name: String,
+        pub port: Option<u16>,
+
+        #[builder(default = 3)]
+        pub retries: usize,
+    }
+}
+
+// Code outside the module of the struct goes through the public items,
+// like code in another crate would.
+use config::{Config, ConfigBuilder, ConfigBuilderComplete};
+
+#[test]
+fn build_through_complete() {
+    let config = Config::builder().name("app".to_string()).port(8080).build();
+
+    assert_eq!(config.name, "app");
+    assert_eq!(config.port, Some(8080));
+    assert_eq!(config.retries, 3);
+}
+
+#[test]
+fn build_through_from() {
+    let config: Config = ConfigBuilder::with_required("app".to_string()).into();
+
+    assert_eq!(config.port, None);
+
+    let config: Config = ConfigBuilder::from(config).retries(5).into();
+
+    assert_eq!(config.name, "app");
+    assert_eq!(config.retries, 5);
+}
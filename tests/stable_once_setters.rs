@@ -0,0 +1,110 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(into_builder)]
+struct MyStruct<'a, T> {
+    req1: &'a str,
+
+    #[builder(once)]
+    opt1: Option<T>,
+
+    #[builder(once, into, name = [opt2, with_opt2])]
+    opt2: Option<String>,
+
+    opt3: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn once_setters() {
+    let my_struct = MyStruct::builder()
+        .opt1(1)
+        .req1("req1")
+        .opt3(3)
+        .with_opt2("opt2")
+        .opt3(4)
+        .def1(5)
+        .build();
+
+    assert_eq!(
+        my_struct,
+        MyStruct {
+            req1: "req1",
+            opt1: Some(1),
+            opt2: Some("opt2".to_string()),
+            opt3: Some(4),
+            def1: 5,
+        }
+    );
+}
+
+#[test]
+fn once_fields_dont_guard_build() {
+    let my_struct = MyStruct::<usize>::builder().req1("req1").build();
+
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.opt2, None);
+}
+
+#[test]
+fn once_fields_are_not_set_by_with_required() {
+    let my_struct = MyStructBuilder::with_required("req1").opt1(1).build();
+    assert_eq!(my_struct.opt1, Some(1));
+
+    // Converting back into the builder doesn't count as calling the setter either.
+    let my_struct = MyStructBuilder::from(my_struct).opt1(2).build();
+    assert_eq!(my_struct.opt1, Some(2));
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+struct Uninit {
+    req1: String,
+
+    #[builder(once)]
+    opt1: Option<String>,
+}
+
+#[test]
+fn maybe_uninit_storage() {
+    let uninit = Uninit::builder()
+        .opt1("opt1".to_string())
+        .req1("req1".to_string())
+        .build();
+
+    assert_eq!(uninit.req1, "req1");
+    assert_eq!(uninit.opt1.as_deref(), Some("opt1"));
+
+    // Dropping a builder only drops what was set.
+    let _ = Uninit::builder().opt1("opt1".to_string());
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Repeated {
+    #[builder(once, each = "arg")]
+    args: Option<Vec<String>>,
+
+    #[builder(once, each = "tag")]
+    tags: Option<Vec<String>>,
+}
+
+#[test]
+fn repeated_setters_of_once_fields() {
+    // The repeated setters can be called in any state, and don't count as calling the setter of the whole value.
+    let repeated = Repeated::builder()
+        .arg("a".to_string())
+        .arg("b".to_string())
+        .tag("x".to_string())
+        .tags(vec!["y".to_string()])
+        .tag("z".to_string())
+        .tags_extend(["w".to_string()])
+        .build();
+
+    assert_eq!(
+        repeated,
+        Repeated {
+            args: Some(vec!["a".to_string(), "b".to_string()]),
+            tags: Some(vec!["y".to_string(), "z".to_string(), "w".to_string()]),
+        }
+    );
+}
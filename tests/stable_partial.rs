@@ -0,0 +1,182 @@
+#[allow(dead_code)]
+#[derive(tidy_builder::Builder)]
+#[builder(partial)]
+struct MyStruct<'a, T> {
+    req1: &'a str,
+    req2: T,
+
+    #[builder(per_index)]
+    coords: [f64; 2],
+
+    #[builder(required)]
+    limit: Option<usize>,
+
+    opt1: Option<&'a str>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s: &MyStruct<'a, T>| s.def1 * 2)]
+    def2: usize,
+
+    #[builder(secret)]
+    password: Option<String>,
+}
+
+#[test]
+fn snapshot_of_the_set_values() {
+    let builder = MyStruct::builder().req1("req1").coords_0(1.0).limit(4);
+    let partial: PartialMyStruct<'_, usize> = builder.partial();
+
+    assert_eq!(partial.req1, Some("req1"));
+    assert_eq!(partial.req2, None);
+    assert_eq!(partial.coords, None);
+    assert_eq!(partial.limit, Some(4));
+    assert_eq!(partial.opt1, None);
+    assert_eq!(partial.def1, Some(3));
+    assert_eq!(partial.def2, None);
+
+    let builder = builder
+        .req2(2)
+        .coords_1(2.0)
+        .opt1("opt1")
+        .def2(5)
+        .password("hunter2".to_string());
+    let partial = builder.partial();
+
+    assert_eq!(partial.req2, Some(2));
+    assert_eq!(partial.coords, Some([1.0, 2.0]));
+    assert_eq!(partial.opt1, Some("opt1"));
+    assert_eq!(partial.def2, Some(5));
+    assert_eq!(partial.password.as_deref(), Some("hunter2"));
+
+    // The builder is still usable after taking a snapshot.
+    assert_eq!(builder.build().def2, 5);
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let builder = MyStruct::builder().req2(2).password("hunter2".to_string());
+
+    assert_eq!(
+        format!("{:?}", builder.partial()),
+        "PartialMyStruct { req1: None, req2: Some(2), coords: None, limit: None, opt1: None, def1: Some(3), def2: None, password: <redacted> }"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(tidy_builder::Builder)]
+#[builder(partial, storage = maybe_uninit)]
+struct Uninit {
+    req1: String,
+    req2: String,
+}
+
+#[test]
+fn maybe_uninit_storage() {
+    let builder = Uninit::builder().req2("req2".to_string());
+    let partial = builder.partial();
+
+    assert_eq!(partial.req1, None);
+    assert_eq!(partial.req2.as_deref(), Some("req2"));
+}
+
+#[allow(dead_code)]
+#[derive(tidy_builder::Builder)]
+#[builder(partial, mode = runtime)]
+struct Runtime {
+    req1: usize,
+    req2: usize,
+}
+
+#[test]
+fn runtime_mode() {
+    let partial = Runtime::builder().req1(1).partial();
+
+    assert_eq!(partial.req1, Some(1));
+    assert_eq!(partial.req2, None);
+}
+
+#[test]
+fn restore_from_parts() {
+    let partial = MyStruct::builder()
+        .req1("req1")
+        .opt1("opt1")
+        .def1(4)
+        .password("hunter2".to_string())
+        .partial();
+
+    // The required fields are set again, the others are restored from the snapshot.
+    let my_struct = MyStruct::from_parts(partial)
+        .req1("other")
+        .req2(2)
+        .coords_0(1.0)
+        .coords_1(2.0)
+        .limit(4)
+        .build();
+
+    assert_eq!(my_struct.req1, "other");
+    assert_eq!(my_struct.opt1, Some("opt1"));
+    assert_eq!(my_struct.def1, 4);
+    assert_eq!(my_struct.def2, 8);
+    assert_eq!(my_struct.password.as_deref(), Some("hunter2"));
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(partial, mode = runtime)]
+struct RuntimeParts {
+    req1: usize,
+
+    #[builder(per_index)]
+    coords: [u8; 2],
+
+    #[builder(required)]
+    limit: Option<usize>,
+
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn restore_every_field_in_runtime_mode() {
+    let partial = RuntimeParts::builder()
+        .req1(1)
+        .coords_0(2)
+        .coords_1(3)
+        .limit(4)
+        .opt1(5)
+        .partial();
+
+    assert_eq!(
+        RuntimeParts::from_parts(partial).try_build(),
+        Ok(RuntimeParts {
+            req1: 1,
+            coords: [2, 3],
+            limit: Some(4),
+            opt1: Some(5),
+            def1: 3,
+        })
+    );
+
+    let partial = RuntimeParts::builder().coords_0(2).partial();
+    assert!(RuntimeParts::from_parts(partial).try_build().is_err());
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(partial)]
+struct Fallible {
+    req1: usize,
+
+    #[builder(try_default = "8080".parse::<u16>())]
+    port: u16,
+}
+
+#[test]
+fn restore_with_fallible_defaults() {
+    let partial = Fallible::builder().unwrap().port(80).partial();
+    let restored = Fallible::from_parts(partial).unwrap();
+
+    assert_eq!(restored.req1(1).build(), Fallible { req1: 1, port: 80 });
+}
@@ -0,0 +1,30 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(per_index)]
+    coords: [f64; 3],
+
+    whole: [u8; 2],
+}
+
+#[test]
+fn per_index_setters() {
+    let my_struct = MyStruct::builder()
+        .coords_2(3.0)
+        .req1(1)
+        .coords_0(1.0)
+        .whole([1, 2])
+        .coords_1(2.0)
+        .build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.coords, [1.0, 2.0, 3.0]);
+    assert_eq!(my_struct.whole, [1, 2]);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.req1(1).coords_0(1.0).coords_2(3.0).whole([1, 2]);
+
+    assert_eq!(builder.build(), Err("missing `coords_1`"));
+}
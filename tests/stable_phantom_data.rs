@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct S<T> {
+    data: u32,
+    marker: PhantomData<T>,
+}
+
+struct Meters;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Distance<Unit> {
+    value: f64,
+    unit: std::marker::PhantomData<fn() -> Unit>,
+}
+
+#[test]
+fn no_setter_for_markers() {
+    let s: S<String> = S::builder().data(1).build();
+    assert_eq!(s.data, 1);
+
+    let distance = Distance::<Meters>::builder().value(2.0).build();
+    assert_eq!(distance.value, 2.0);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = S::<String>::builder_mut();
+    builder.data(1);
+
+    assert_eq!(builder.build().unwrap().data, 1);
+}
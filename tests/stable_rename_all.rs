@@ -0,0 +1,72 @@
+#[derive(tidy_builder::Builder)]
+#[builder(rename_all = "camelCase", both_modes)]
+struct MyStruct {
+    max_size: usize,
+
+    #[builder(each = "item")]
+    item_list: Option<Vec<String>>,
+
+    #[builder(name = "min_size", default = 1)]
+    min_size: usize,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(rename_all = "camelCase")]
+struct Explicit {
+    #[builder(each = "add_tag")]
+    tag_list: Vec<String>,
+
+    #[builder(alias = "set_max_size")]
+    max_size: Option<usize>,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(rename_all = "PascalCase", setter_prefix = "with_")]
+struct Prefixed {
+    #[builder(into)]
+    label: String,
+}
+
+#[test]
+fn rename_all() {
+    let my_struct = MyStruct::builder()
+        .maxSize(10)
+        .item("item".to_string())
+        .itemListExtend(["other".to_string()])
+        .min_size(2)
+        .build();
+
+    assert_eq!(my_struct.max_size, 10);
+    assert_eq!(
+        my_struct.item_list,
+        Some(vec!["item".to_string(), "other".to_string()])
+    );
+    assert_eq!(my_struct.min_size, 2);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.maxSize(3);
+
+    assert_eq!(builder.build().unwrap().max_size, 3);
+
+    let prefixed = Prefixed::builder().WithLabel("label").build();
+    assert_eq!(prefixed.label, "label");
+
+    let prefixed = Prefixed::builder().WithLabelStr("label").build();
+    assert_eq!(prefixed.label, "label");
+}
+
+#[test]
+fn explicit_names_are_verbatim() {
+    let explicit = Explicit::builder()
+        .add_tag("a".to_string())
+        .add_tag("b".to_string())
+        .tagListExtend(["c".to_string()])
+        .set_max_size(3)
+        .build();
+
+    assert_eq!(explicit.tag_list, ["a", "b", "c"]);
+    assert_eq!(explicit.max_size, Some(3));
+
+    let explicit = Explicit::builder().maxSize(4).tagList(vec![]).build();
+    assert_eq!(explicit.max_size, Some(4));
+}
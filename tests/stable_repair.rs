@@ -0,0 +1,47 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(repair = clamp, both_modes)]
+struct Volume {
+    level: u8,
+    balance: i8,
+}
+
+fn clamp(volume: Volume) -> Volume {
+    Volume {
+        level: volume.level.min(100),
+        balance: volume.balance.clamp(-10, 10),
+    }
+}
+
+#[test]
+fn repair() {
+    let volume = Volume::builder().level(150).balance(-20).build();
+
+    assert_eq!(
+        volume,
+        Volume {
+            level: 100,
+            balance: -10
+        }
+    );
+
+    let volume = Volume::builder().level(50).balance(5).build();
+
+    assert_eq!(
+        volume,
+        Volume {
+            level: 50,
+            balance: 5
+        }
+    );
+
+    let mut builder = Volume::builder_mut();
+    builder.level(101).balance(11);
+
+    assert_eq!(
+        builder.build(),
+        Ok(Volume {
+            level: 100,
+            balance: 10
+        })
+    );
+}
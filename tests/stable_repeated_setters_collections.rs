@@ -0,0 +1,59 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Request {
+    #[builder(each = "header")]
+    headers: BTreeMap<String, String>,
+
+    #[builder(each = "param")]
+    params: Option<HashMap<&'static str, usize>>,
+
+    #[builder(default, each = "tag")]
+    tags: BTreeSet<u8>,
+
+    #[builder(default, each = "hop", extend = "route")]
+    hops: VecDeque<u16>,
+}
+
+#[test]
+fn maps_and_sets() {
+    let request = Request::builder()
+        .header(("user-agent".to_string(), "curl".to_string()))
+        .header(("accept".to_string(), "*/*".to_string()))
+        .header(("host".to_string(), "localhost".to_string()))
+        .param(("page", 2))
+        .tag(3)
+        .tag(1)
+        .tag(3)
+        .hop(80)
+        .route([443, 8080])
+        .build();
+
+    // The items are inserted, so the map keeps its keys in order.
+    let names: Vec<_> = request.headers.keys().map(String::as_str).collect();
+    assert_eq!(names, ["accept", "host", "user-agent"]);
+
+    assert_eq!(request.params, Some(HashMap::from([("page", 2)])));
+    assert_eq!(request.tags.into_iter().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(request.hops, [80, 443, 8080]);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Request::builder_mut();
+    for (name, value) in [("b", "2"), ("a", "1")] {
+        builder.header((name.to_string(), value.to_string()));
+    }
+
+    let request = builder.build().unwrap();
+
+    assert_eq!(
+        request.headers,
+        BTreeMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string())
+        ])
+    );
+    assert_eq!(request.params, None);
+}
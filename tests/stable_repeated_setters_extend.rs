@@ -0,0 +1,32 @@
+#[derive(tidy_builder::Builder)]
+pub struct MyStruct {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+
+    #[builder(each = "opt_arg", extend = "add_opt_args")]
+    opt_args: Option<Vec<usize>>,
+
+    #[builder(default, extend = "add_def_args")]
+    def_args: Vec<usize>,
+}
+
+#[test]
+fn repeated_setters_extend() {
+    let my_struct = MyStruct::builder()
+        .arg("arg1".to_string())
+        .args_extend(vec!["arg2".to_string(), "arg3".to_string()])
+        .add_opt_args(1..3)
+        .opt_arg(3)
+        .add_def_args([1, 2])
+        .add_def_args(Some(3))
+        .build();
+
+    assert_eq!(my_struct.args, ["arg1", "arg2", "arg3"]);
+    assert_eq!(my_struct.opt_args, Some(vec![1, 2, 3]));
+    assert_eq!(my_struct.def_args, [1, 2, 3]);
+
+    let my_struct = MyStruct::builder().args_extend(std::iter::empty()).build();
+
+    assert!(my_struct.args.is_empty());
+    assert_eq!(my_struct.opt_args, None);
+}
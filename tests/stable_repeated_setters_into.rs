@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Command {
+    #[builder(into, each = "arg")]
+    args: Vec<String>,
+
+    #[builder(into, each = "env")]
+    envs: Option<Vec<String>>,
+
+    #[builder(default, into, each = "path")]
+    paths: Vec<PathBuf>,
+
+    #[builder(default, each = "flag")]
+    flags: Vec<String>,
+}
+
+#[test]
+fn push_items_that_convert() {
+    let command = Command::builder()
+        .arg("-l")
+        .arg(String::from("-a"))
+        .env("HOME=/root")
+        .path("/bin")
+        .flag("-v".to_string())
+        .build();
+
+    assert_eq!(command.args, ["-l", "-a"]);
+    assert_eq!(command.envs, Some(vec!["HOME=/root".to_string()]));
+    assert_eq!(command.paths, [PathBuf::from("/bin")]);
+    assert_eq!(command.flags, ["-v"]);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Command::builder_mut();
+    for arg in ["-l", "-a"] {
+        builder.arg(arg);
+    }
+
+    assert_eq!(builder.build().unwrap().args, ["-l", "-a"]);
+}
+
+// `into` on the struct applies to the repeated setters too.
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(into)]
+struct Input {
+    #[builder(each = "line")]
+    lines: Vec<String>,
+}
+
+#[test]
+fn into_on_the_struct() {
+    let input = Input::builder().line("first").line("second").build();
+
+    assert_eq!(input.lines, ["first", "second"]);
+}
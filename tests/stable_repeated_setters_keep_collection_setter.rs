@@ -0,0 +1,35 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    #[builder(each = "args", keep_collection_setter)]
+    args: Vec<String>,
+
+    #[builder(each = "tags", keep_collection_setter)]
+    tags: Option<Vec<String>>,
+
+    #[builder(default, each = "nums", keep_collection_setter)]
+    nums: Vec<usize>,
+}
+
+#[test]
+fn repeated_setters_keep_collection_setter() {
+    let my_struct = MyStruct::builder()
+        .args("a".to_string())
+        .args_all(vec!["b".to_string(), "c".to_string()])
+        .args("d".to_string())
+        .tags_all(vec!["t".to_string()])
+        .nums(1)
+        .nums_all(vec![2, 3])
+        .build();
+
+    assert_eq!(my_struct.args, vec!["b", "c", "d"]);
+    assert_eq!(my_struct.tags, Some(vec!["t".to_string()]));
+    assert_eq!(my_struct.nums, vec![2, 3]);
+
+    let mut builder = MyStruct::builder_mut();
+    builder
+        .args_all(vec!["a".to_string()])
+        .args("b".to_string());
+
+    assert_eq!(builder.build().unwrap().args, vec!["a", "b"]);
+}
@@ -0,0 +1,39 @@
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static PUSHES: Cell<usize> = const { Cell::new(0) };
+    static PUSHED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(item: &str) {
+    PUSHED.with(|pushed| pushed.borrow_mut().push(item.to_string()));
+}
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(each = "arg", on_push = |_| PUSHES.with(|pushes| pushes.set(pushes.get() + 1)))]
+    args: Vec<String>,
+
+    #[builder(each = "tag", on_push = record)]
+    tags: Option<Vec<String>>,
+
+    #[builder(default, each = "num", on_push = |num: &usize| assert!(*num < 10))]
+    nums: Vec<usize>,
+}
+
+#[test]
+fn repeated_setters_on_push() {
+    let my_struct = MyStruct::builder()
+        .arg("a".to_string())
+        .arg("b".to_string())
+        .tag("t".to_string())
+        .num(1)
+        .build();
+
+    assert_eq!(my_struct.args, vec!["a", "b"]);
+    assert_eq!(my_struct.tags, Some(vec!["t".to_string()]));
+    assert_eq!(my_struct.nums, vec![1]);
+
+    assert_eq!(PUSHES.with(Cell::get), 2);
+    assert_eq!(PUSHED.with(|pushed| pushed.borrow().clone()), vec!["t"]);
+}
@@ -0,0 +1,32 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+pub struct MyStruct {
+    #[builder(each = "arg")]
+    args: Option<Vec<String>>,
+}
+
+#[test]
+fn replace_setter() {
+    let my_struct = MyStruct::builder()
+        .arg("arg1".to_string())
+        .set_args(Some(vec!["arg2".to_string()]))
+        .arg("arg3".to_string())
+        .build();
+
+    assert_eq!(
+        my_struct.args,
+        Some(vec!["arg2".to_string(), "arg3".to_string()])
+    );
+
+    let my_struct = MyStruct::builder()
+        .arg("arg1".to_string())
+        .set_args(None)
+        .build();
+
+    assert_eq!(my_struct.args, None);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.arg("arg1".to_string()).set_args(None);
+
+    assert_eq!(builder.build().unwrap().args, None);
+}
@@ -0,0 +1,47 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+pub struct MyStruct {
+    // Always starts with the seed, which can only be appended to.
+    #[builder(value = vec!["--verbose".to_string()], each = "arg")]
+    args: Vec<String>,
+
+    // Starts with the seed, which the whole-collection setter replaces.
+    #[builder(default = vec![1, 2], each = "port")]
+    ports: Vec<u16>,
+}
+
+#[test]
+fn seeded_by_value() {
+    let my_struct = MyStruct::builder().build();
+    assert_eq!(my_struct.args, ["--verbose"]);
+
+    let my_struct = MyStruct::builder()
+        .arg("--color".to_string())
+        .args_extend(["-j".to_string(), "4".to_string()])
+        .build();
+    assert_eq!(my_struct.args, ["--verbose", "--color", "-j", "4"]);
+}
+
+#[test]
+fn seeded_by_default() {
+    let my_struct = MyStruct::builder().port(3).build();
+    assert_eq!(my_struct.ports, [1, 2, 3]);
+
+    // Setting the whole collection replaces the seed, and the repeated setter appends to the new value.
+    let my_struct = MyStruct::builder().ports(vec![8080]).port(8081).build();
+    assert_eq!(my_struct.ports, [8080, 8081]);
+
+    // Setting the whole collection after the repeated setter replaces everything.
+    let my_struct = MyStruct::builder().port(3).ports(vec![8080]).build();
+    assert_eq!(my_struct.ports, [8080]);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = MyStruct::builder_mut();
+    builder.arg("--color".to_string()).port(3);
+
+    let my_struct = builder.build().unwrap();
+    assert_eq!(my_struct.args, ["--verbose", "--color"]);
+    assert_eq!(my_struct.ports, [1, 2, 3]);
+}
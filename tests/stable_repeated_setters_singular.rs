@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Request {
+    #[builder(each)]
+    headers: Vec<(&'static str, &'static str)>,
+
+    #[builder(each)]
+    entries: Option<Vec<u8>>,
+
+    #[builder(default, each)]
+    status: Vec<u16>,
+
+    #[builder(default, each)]
+    data: HashSet<u8>,
+
+    #[builder(default, each)]
+    types: Vec<String>,
+
+    #[builder(default, each)]
+    r#items: Vec<u8>,
+
+    #[builder(default, each)]
+    addresses: Vec<&'static str>,
+
+    #[builder(default, each)]
+    boxes: Vec<u8>,
+
+    #[builder(default, each)]
+    matches: Vec<u8>,
+
+    #[builder(default, each)]
+    dishes: Vec<u8>,
+
+    #[builder(default, each)]
+    prefixes: Vec<u8>,
+
+    #[builder(default, each)]
+    batches: Vec<u8>,
+
+    #[builder(default, each = "cache")]
+    caches: Vec<u8>,
+}
+
+#[test]
+fn singular_names() {
+    let request = Request::builder()
+        .header(("accept", "*/*"))
+        .header(("host", "localhost"))
+        .entry(1)
+        .status_item(200)
+        .data_item(3)
+        .types_item("text/plain".to_string())
+        .item(4)
+        .item(5)
+        .address("localhost")
+        .boxes_item(6)
+        .matches_item(7)
+        .dish(8)
+        .prefix(10)
+        .batch(11)
+        .cache(9)
+        .build();
+
+    assert_eq!(request.headers.len(), 2);
+    assert_eq!(request.entries, Some(vec![1]));
+    assert_eq!(request.status, [200]);
+    assert!(request.data.contains(&3));
+    assert_eq!(request.types, ["text/plain"]);
+    assert_eq!(request.items, [4, 5]);
+    assert_eq!(request.addresses, ["localhost"]);
+    assert_eq!(request.boxes, [6]);
+    assert_eq!(request.matches, [7]);
+    assert_eq!(request.dishes, [8]);
+    assert_eq!(request.caches, [9]);
+    assert_eq!(request.prefixes, [10]);
+    assert_eq!(request.batches, [11]);
+}
+
+#[test]
+fn whole_collection_setters_are_kept() {
+    let request = Request::builder()
+        .headers(vec![("host", "localhost")])
+        .status(vec![404])
+        .build();
+
+    assert_eq!(request.headers, [("host", "localhost")]);
+    assert_eq!(request.status, [404]);
+}
@@ -0,0 +1,49 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(required)]
+    req2: Option<String>,
+
+    #[builder(required, each = "item")]
+    req3: Option<Vec<usize>>,
+
+    opt1: Option<usize>,
+}
+
+#[test]
+fn required_option() {
+    let my_struct = MyStruct::builder()
+        .req2("req2".to_string())
+        .req1(1)
+        .item(1)
+        .item(2)
+        .build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.req2, Some("req2".to_string()));
+    assert_eq!(my_struct.req3, Some(vec![1, 2]));
+    assert_eq!(my_struct.opt1, None);
+
+    let my_struct = MyStructBuilder::with_required(1, "req2".to_string(), vec![]).build();
+
+    assert_eq!(my_struct.req2, Some("req2".to_string()));
+    assert_eq!(my_struct.req3, Some(vec![]));
+}
+
+#[test]
+fn required_option_mut() {
+    let mut builder = MyStruct::builder_mut();
+    builder.req1(1).req3(vec![3]);
+
+    assert_eq!(builder.build().err(), Some("missing `req2`"));
+
+    let mut builder = MyStruct::builder_mut();
+    builder.req1(1).req2("req2".to_string()).item(3);
+
+    let my_struct = builder.build().unwrap();
+
+    assert_eq!(my_struct.req2, Some("req2".to_string()));
+    assert_eq!(my_struct.req3, Some(vec![3]));
+}
@@ -0,0 +1,98 @@
+#[derive(Debug, Clone, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime, derive(Clone))]
+struct MyStruct<T> {
+    req1: T,
+    req2: usize,
+
+    #[builder(per_index)]
+    coords: [f64; 2],
+
+    #[builder(each = "arg")]
+    args: Vec<String>,
+
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+
+    #[builder(default_from = |s: &MyStruct<T>| s.def1 * 2)]
+    def2: usize,
+}
+
+#[test]
+fn setters_return_the_same_type() {
+    let mut builder: MyStructBuilder<&str> = MyStruct::builder().req1("req1");
+
+    for arg in ["a", "b"] {
+        builder = builder.arg(arg.to_string());
+    }
+
+    if builder.clone().try_build().is_err() {
+        builder = builder.req2(2);
+    }
+
+    let my_struct = builder
+        .coords_0(1.0)
+        .coords_1(2.0)
+        .opt1(1)
+        .try_build()
+        .unwrap();
+
+    assert_eq!(my_struct.req1, "req1");
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.coords, [1.0, 2.0]);
+    assert_eq!(my_struct.args, vec!["a", "b"]);
+    assert_eq!(my_struct.opt1, Some(1));
+    assert_eq!(my_struct.def1, 3);
+    assert_eq!(my_struct.def2, 6);
+}
+
+#[test]
+fn missing_required_fields() {
+    let builder = MyStruct::builder().req1(1).coords_0(1.0).coords_1(2.0);
+
+    assert_eq!(builder.try_build(), Err("missing `req2`"));
+
+    let builder = MyStruct::builder()
+        .req1(1)
+        .req2(2)
+        .args(vec![])
+        .coords_0(1.0);
+
+    assert_eq!(builder.try_build(), Err("missing `coords_1`"));
+}
+
+#[test]
+fn with_required() {
+    let my_struct = MyStructBuilder::with_required("req1", 2, vec![], [1.0, 2.0])
+        .def1(4)
+        .try_build()
+        .unwrap();
+
+    assert_eq!(my_struct.coords, [1.0, 2.0]);
+    assert_eq!(my_struct.def1, 4);
+    assert_eq!(my_struct.def2, 8);
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(mode = runtime)]
+struct AllOptional {
+    opt1: Option<usize>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn default_without_required_fields() {
+    assert_eq!(
+        AllOptional::default(),
+        AllOptional {
+            opt1: None,
+            def1: 3
+        }
+    );
+
+    let builder: AllOptionalBuilder = Default::default();
+    assert_eq!(builder.opt1(1).try_build().unwrap().opt1, Some(1));
+}
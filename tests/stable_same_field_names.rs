@@ -0,0 +1,27 @@
+#[derive(tidy_builder::Builder)]
+struct User {
+    name: String,
+    id: usize,
+}
+
+#[derive(tidy_builder::Builder)]
+struct Group {
+    name: String,
+    id: usize,
+    members: Option<Vec<usize>>,
+}
+
+#[test]
+fn same_field_names() {
+    let user = User::builder().name("foo".to_string()).id(1).build();
+    let group = Group::builder()
+        .id(2)
+        .name("bar".to_string())
+        .members(vec![user.id])
+        .build();
+
+    assert_eq!(user.name, "foo");
+    assert_eq!(group.name, "bar");
+    assert_eq!(group.id, 2);
+    assert_eq!(group.members, Some(vec![1]));
+}
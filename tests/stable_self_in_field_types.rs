@@ -0,0 +1,25 @@
+#[derive(tidy_builder::Builder)]
+struct Node<T> {
+    value: T,
+    next: Option<Box<Self>>,
+
+    #[builder(each = "child")]
+    children: Vec<Self>,
+}
+
+#[test]
+fn self_in_field_types() {
+    let leaf = Node::builder().value(3).children(vec![]).build();
+    let child = Node::builder().value(2).children(vec![]).build();
+    let node = Node::builder()
+        .value(1)
+        .next(Box::new(leaf))
+        .child(child)
+        .build();
+
+    assert_eq!(node.value, 1);
+    assert_eq!(node.next.as_ref().unwrap().value, 3);
+    assert!(node.next.as_ref().unwrap().next.is_none());
+    assert_eq!(node.children.len(), 1);
+    assert_eq!(node.children[0].value, 2);
+}
@@ -0,0 +1,35 @@
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(setter = set_if_some)]
+    opt1: Option<String>,
+
+    #[builder(default = 3, setter = set_if_some)]
+    def1: usize,
+}
+
+#[test]
+fn set_if_some_setters() {
+    let my_struct = MyStruct::builder().req1(1).opt1(None).def1(None).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.opt1, None);
+    assert_eq!(my_struct.def1, 3);
+
+    let my_struct = MyStruct::builder()
+        .req1(1)
+        .opt1(Some("opt1".to_string()))
+        .def1(Some(4))
+        .def1(None)
+        .build();
+
+    assert_eq!(my_struct.opt1, Some("opt1".to_string()));
+    assert_eq!(my_struct.def1, 4);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.req1(1).def1(Some(5)).def1(None);
+
+    assert_eq!(builder.build().unwrap().def1, 5);
+}
@@ -0,0 +1,105 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    #[builder(name = [set_req1, with_req1, req1])]
+    req1: usize,
+
+    #[builder(name = ["opt1", "with_opt1"])]
+    opt1: Option<usize>,
+
+    #[builder(default, name = [def1, with_def1])]
+    def1: usize,
+
+    // The alias named like the repeated setter is omitted.
+    #[builder(each = "arg", name = [args, arg, with_args])]
+    args: Vec<String>,
+}
+
+#[test]
+fn aliases_move_to_the_same_state() {
+    let expected = MyStruct {
+        req1: 1,
+        opt1: Some(2),
+        def1: 3,
+        args: vec!["a".to_string()],
+    };
+
+    let my_struct = MyStruct::builder()
+        .set_req1(1)
+        .opt1(2)
+        .def1(3)
+        .arg("a".to_string())
+        .build();
+    assert_eq!(my_struct, expected);
+
+    let my_struct = MyStruct::builder()
+        .with_req1(1)
+        .with_opt1(2)
+        .with_def1(3)
+        .with_args(vec!["a".to_string()])
+        .build();
+    assert_eq!(my_struct, expected);
+
+    let my_struct = MyStruct::builder()
+        .req1(1)
+        .opt1(2)
+        .def1(3)
+        .args(vec!["a".to_string()])
+        .build();
+    assert_eq!(my_struct, expected);
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = MyStruct::builder_mut();
+    builder
+        .with_req1(1)
+        .with_opt1(2)
+        .with_def1(3)
+        .arg("a".to_string());
+
+    assert_eq!(builder.build().unwrap().req1, 1);
+}
+
+// Unlike `name`, `alias` keeps the setter named after the field.
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+struct Server {
+    #[builder(alias = "address")]
+    host: String,
+
+    #[builder(alias = [listen_on, bind])]
+    port: u16,
+
+    #[builder(name = [timeout_secs, timeout], alias = "wait")]
+    timeout: Option<u64>,
+}
+
+#[test]
+fn alias_keeps_the_setter() {
+    let expected = Server {
+        host: "localhost".to_string(),
+        port: 80,
+        timeout: Some(5),
+    };
+
+    let server = Server::builder()
+        .host("localhost".to_string())
+        .port(80)
+        .timeout_secs(5)
+        .build();
+    assert_eq!(server, expected);
+
+    let server = Server::builder()
+        .address("localhost".to_string())
+        .listen_on(80)
+        .timeout(5)
+        .build();
+    assert_eq!(server, expected);
+
+    let server = Server::builder()
+        .bind(80)
+        .wait(5)
+        .address("localhost".to_string())
+        .build();
+    assert_eq!(server, expected);
+}
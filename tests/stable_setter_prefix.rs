@@ -0,0 +1,63 @@
+#[derive(tidy_builder::Builder)]
+#[builder(setter_prefix = "with_", both_modes)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(name = "set_req2")]
+    req2: usize,
+
+    #[builder(each = "arg")]
+    args: Vec<String>,
+
+    #[builder(each = "with_tag")]
+    tags: Option<Vec<String>>,
+
+    #[builder(default)]
+    def1: usize,
+}
+
+#[test]
+fn setter_prefix() {
+    let my_struct = MyStruct::builder()
+        .with_req1(1)
+        .set_req2(2)
+        .arg("a".to_string())
+        .with_args_extend(vec!["b".to_string()])
+        .with_tag("t".to_string())
+        .with_def1(3)
+        .build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.req2, 2);
+    assert_eq!(my_struct.args, vec!["a", "b"]);
+    assert_eq!(my_struct.tags, Some(vec!["t".to_string()]));
+    assert_eq!(my_struct.def1, 3);
+
+    let mut builder = MyStruct::builder_mut();
+    builder.with_req1(1).set_req2(2).with_args(vec![]);
+
+    assert!(builder.build().is_ok());
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(setter_prefix = type)]
+struct HttpServer {
+    port: u16,
+    host: Option<String>,
+
+    #[builder(name = "timeout")]
+    timeout_secs: Option<u64>,
+}
+
+#[test]
+fn type_setter_prefix() {
+    let server = HttpServer::builder()
+        .http_server_port(8080)
+        .http_server_host("localhost".to_string())
+        .timeout(30)
+        .build();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host.as_deref(), Some("localhost"));
+    assert_eq!(server.timeout_secs, Some(30));
+}
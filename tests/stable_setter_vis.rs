@@ -0,0 +1,65 @@
+mod config {
+    #[derive(Debug, tidy_builder::Builder)]
+    #[builder(both_modes, setters_trait)]
+    pub struct Config {
+        pub name: String,
+        pub host: Option<String>,
+
+        #[builder(vis = "pub(crate)")]
+        pub port: Option<u16>,
+
+        // Only the module of the struct can set the secret.
+        #[builder(vis = "", default)]
+        pub secret: String,
+    }
+
+    pub fn with_secret(builder: ConfigBuilder<false>) -> ConfigBuilder<false> {
+        builder.secret("hunter2".to_string())
+    }
+
+    pub fn with_secret_mut(builder: &mut ConfigBuilderMut) {
+        builder.secret("hunter2".to_string());
+    }
+}
+
+use config::{Config, ConfigBuilderComplete, ConfigSetters};
+
+#[test]
+fn crate_visible_setter() {
+    let config = Config::builder().name("app".to_string()).port(8080).build();
+
+    assert_eq!(config.name, "app");
+    assert_eq!(config.port, Some(8080));
+}
+
+#[test]
+fn private_setter() {
+    let config = config::with_secret(Config::builder())
+        .name("app".to_string())
+        .build();
+
+    assert_eq!(config.secret, "hunter2");
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Config::builder_mut();
+    builder.name("app".to_string()).port(80);
+    config::with_secret_mut(&mut builder);
+
+    let config = builder.build().unwrap();
+    assert_eq!(config.port, Some(80));
+    assert_eq!(config.secret, "hunter2");
+}
+
+// The setters trait only declares the public setters, so it has no `port`.
+fn set_host<B: ConfigSetters>(builder: B) -> B {
+    builder.host("localhost".to_string())
+}
+
+#[test]
+fn setters_trait() {
+    let config = set_host(Config::builder()).name("app".to_string()).build();
+
+    assert_eq!(config.host.as_deref(), Some("localhost"));
+}
@@ -0,0 +1,60 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(setters_trait)]
+struct Widget<'a, T> {
+    name: &'a str,
+    value: T,
+
+    width: Option<usize>,
+
+    #[builder(each = "tag")]
+    tags: Option<Vec<String>>,
+
+    #[builder(default = 1, name = ["height", "with_height"])]
+    height: usize,
+
+    #[builder(default, into)]
+    label: String,
+
+    #[builder(default, check = |depth: &usize| if *depth < 10 { Ok(()) } else { Err("too deep") })]
+    depth: usize,
+}
+
+// Works on a builder in any state.
+fn square<'a, B: WidgetSetters<'a, usize>>(builder: B, side: usize) -> B {
+    builder.width(side).height(side).label("square")
+}
+
+#[test]
+fn generic_over_states() {
+    let widget = square(Widget::builder(), 2).name("a").value(1).build();
+
+    assert_eq!((widget.width, widget.height), (Some(2), 2));
+    assert_eq!(widget.label, "square");
+
+    let widget = square(Widget::builder().name("b").value(1), 3)
+        .tag("t".to_string())
+        .build();
+
+    assert_eq!(widget.width, Some(3));
+    assert_eq!(widget.tags, Some(vec!["t".to_string()]));
+}
+
+fn deep<'a, B: WidgetSetters<'a, usize>>(builder: B) -> Result<B, WidgetBuilderError> {
+    WidgetSetters::depth(builder, 20)
+}
+
+#[test]
+fn checked_setters() {
+    assert!(deep(Widget::builder()).is_err());
+}
+
+#[test]
+fn with_apply() {
+    let widget = Widget::builder()
+        .name("c")
+        .apply(|b| square(b, 4))
+        .value(1)
+        .build();
+
+    assert_eq!(widget.height, 4);
+}
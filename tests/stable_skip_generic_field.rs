@@ -0,0 +1,14 @@
+#[derive(tidy_builder::Builder)]
+struct S<T: Default> {
+    #[builder(skip, default)]
+    cache: T,
+    req: u32,
+}
+
+#[test]
+fn skipped_generic_field() {
+    let s = S::<Vec<u8>>::builder().req(1).build();
+
+    assert_eq!(s.cache, Vec::<u8>::new());
+    assert_eq!(s.req, 1);
+}
@@ -0,0 +1,26 @@
+fn compute() -> usize {
+    42
+}
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(skip, value = compute())]
+    computed1: usize,
+
+    #[builder(value = String::from("computed"))]
+    computed2: String,
+
+    req2: usize,
+}
+
+#[test]
+fn skip_required_with_value() {
+    let my_struct = MyStruct::builder().req1(1).req2(2).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.computed1, 42);
+    assert_eq!(my_struct.computed2, "computed");
+    assert_eq!(my_struct.req2, 2);
+}
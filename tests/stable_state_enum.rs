@@ -0,0 +1,104 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(state_enum)]
+struct Connection {
+    host: String,
+    port: u16,
+    timeout: Option<u64>,
+
+    #[builder(once)]
+    user: Option<String>,
+}
+
+#[test]
+fn builders_in_different_states() {
+    let states: Vec<ConnectionBuilderState> = vec![
+        Connection::builder().into_state(),
+        Connection::builder().port(80).into_state(),
+        Connection::builder()
+            .host("localhost".to_string())
+            .port(8080)
+            .timeout(5)
+            .into_state(),
+        Connection::builder()
+            .user("admin".to_string())
+            .host("example.com".to_string())
+            .port(22)
+            .into_state(),
+    ];
+
+    let mut built = vec![];
+    for state in states {
+        match state {
+            ConnectionBuilderState::Init(_) => {}
+            ConnectionBuilderState::WithPort(builder) => {
+                built.push(builder.host("fallback".to_string()).build());
+            }
+            ConnectionBuilderState::WithHostAndPort(builder) => built.push(builder.build()),
+            ConnectionBuilderState::WithHostAndPortAndUser(builder) => {
+                built.push(builder.build());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        built,
+        vec![
+            Connection {
+                host: "fallback".to_string(),
+                port: 80,
+                timeout: None,
+                user: None,
+            },
+            Connection {
+                host: "localhost".to_string(),
+                port: 8080,
+                timeout: Some(5),
+                user: None,
+            },
+            Connection {
+                host: "example.com".to_string(),
+                port: 22,
+                timeout: None,
+                user: Some("admin".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn from_a_builder() {
+    let state = ConnectionBuilderState::from(Connection::builder().host("localhost".to_string()));
+
+    assert!(matches!(state, ConnectionBuilderState::WithHost(_)));
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(state_enum)]
+struct Wrapper<'a, T, const N: usize> {
+    values: &'a [T; N],
+    label: Option<&'a str>,
+}
+
+#[test]
+fn generic_struct() {
+    let values = [1, 2, 3];
+    let states: Vec<WrapperBuilderState<'_, 3, i32>> = vec![
+        Wrapper::builder().label("none").into_state(),
+        Wrapper::builder().values(&values).into_state(),
+    ];
+
+    let built: Vec<(i32, Option<&str>)> = states
+        .into_iter()
+        .filter_map(|state| match state {
+            WrapperBuilderState::Init(_) => None,
+            WrapperBuilderState::WithValues(builder) => {
+                let wrapper = builder.build();
+
+                Some((wrapper.values.iter().sum(), wrapper.label))
+            }
+        })
+        .collect();
+
+    assert_eq!(built, vec![(6, None)]);
+}
@@ -0,0 +1,81 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(str_setters, both_modes)]
+struct Person {
+    name: String,
+    nickname: Option<String>,
+
+    #[builder(default = String::from("unknown"))]
+    city: std::string::String,
+
+    #[builder(into)]
+    email: String,
+
+    #[builder(to_string)]
+    id: String,
+
+    age: u8,
+}
+
+#[test]
+fn borrowed_strings() {
+    let person = Person::builder()
+        .name("alice")
+        .nickname("ali")
+        .email(String::from("alice@example.com"))
+        .id(42)
+        .age(30)
+        .build();
+
+    assert_eq!(
+        person,
+        Person {
+            name: "alice".to_string(),
+            nickname: Some("ali".to_string()),
+            city: "unknown".to_string(),
+            email: "alice@example.com".to_string(),
+            id: "42".to_string(),
+            age: 30,
+        }
+    );
+}
+
+#[test]
+fn owned_string_as_str() {
+    let city = String::from("Paris");
+
+    let person = Person::builder()
+        .name(&city)
+        .city(&city)
+        .email("bob@example.com")
+        .id("bob")
+        .age(40)
+        .build();
+
+    assert_eq!(person.name, "Paris");
+    assert_eq!(person.city, city);
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(str_setters, mode = runtime)]
+struct Runtime {
+    name: String,
+}
+
+#[test]
+fn runtime_mode() {
+    let runtime = Runtime::builder().name("name").try_build().unwrap();
+
+    assert_eq!(runtime.name, "name");
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Person::builder_mut();
+    builder
+        .name("carol")
+        .email("carol@example.com")
+        .id(1)
+        .age(20);
+
+    assert_eq!(builder.build().unwrap().name, "carol");
+}
@@ -0,0 +1,27 @@
+struct Token(String);
+
+#[derive(tidy_builder::Builder)]
+#[builder(struct_debug)]
+struct Credentials<T> {
+    user: String,
+
+    #[builder(secret)]
+    token: Token,
+
+    port: Option<T>,
+}
+
+#[test]
+fn struct_debug() {
+    let credentials = Credentials::builder()
+        .user("user".to_string())
+        .token(Token("token".to_string()))
+        .port(80)
+        .build();
+
+    assert_eq!(credentials.token.0, "token");
+    assert_eq!(
+        format!("{credentials:?}"),
+        r#"Credentials { user: "user", token: <redacted>, port: Some(80) }"#
+    );
+}
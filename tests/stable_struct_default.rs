@@ -0,0 +1,67 @@
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(default)]
+struct Config<T>
+where
+    T: Clone,
+{
+    name: String,
+    values: Vec<T>,
+
+    #[builder(per_index)]
+    origin: [i32; 2],
+
+    #[builder(required)]
+    limit: Option<usize>,
+
+    port: Option<u16>,
+
+    #[builder(default = 3)]
+    retries: usize,
+}
+
+#[test]
+fn required_fields_get_their_default_value() {
+    let config = Config::<u8>::default();
+
+    assert_eq!(
+        config,
+        Config {
+            name: String::new(),
+            values: vec![],
+            origin: [0, 0],
+            // Like with `build_with_defaults`, a required `Option` gets the default value of its inner type.
+            limit: Some(0),
+            port: None,
+            retries: 3,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(default, mode = runtime)]
+struct Runtime {
+    req1: usize,
+    opt1: Option<usize>,
+}
+
+#[test]
+fn runtime_mode() {
+    assert_eq!(
+        Runtime::default(),
+        Runtime {
+            req1: 0,
+            opt1: None
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, tidy_builder::Builder)]
+#[builder(default, storage = maybe_uninit)]
+struct Slots {
+    req1: String,
+}
+
+#[test]
+fn maybe_uninit_storage() {
+    assert_eq!(Slots::default().req1, "");
+}
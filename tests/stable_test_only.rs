@@ -0,0 +1,31 @@
+mod fixtures {
+    #[derive(tidy_builder::Builder)]
+    #[builder(test_only)]
+    pub struct MyStruct {
+        req1: usize,
+        opt1: Option<usize>,
+    }
+
+    impl MyStruct {
+        pub fn req1(&self) -> usize {
+            self.req1
+        }
+
+        pub fn opt1(&self) -> Option<usize> {
+            self.opt1
+        }
+    }
+
+    #[cfg(test)]
+    pub fn my_struct() -> MyStruct {
+        MyStruct::builder().req1(1).opt1(2).build()
+    }
+}
+
+#[test]
+fn test_only() {
+    let my_struct = fixtures::my_struct();
+
+    assert_eq!(my_struct.req1(), 1);
+    assert_eq!(my_struct.opt1(), Some(2));
+}
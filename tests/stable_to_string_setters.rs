@@ -0,0 +1,45 @@
+use std::fmt;
+use std::rc::Rc;
+
+struct Version(u8, u8);
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(both_modes)]
+struct MyStruct {
+    #[builder(to_string)]
+    id: String,
+
+    #[builder(to_string)]
+    version: Option<String>,
+
+    #[builder(default, to_string, setter = wrap)]
+    label: Rc<String>,
+}
+
+#[test]
+fn to_string_setters() {
+    let my_struct = MyStruct::builder()
+        .id(42)
+        .version(Version(1, 2))
+        .label('x')
+        .build();
+
+    assert_eq!(my_struct.id, "42");
+    assert_eq!(my_struct.version, Some("1.2".to_string()));
+    assert_eq!(*my_struct.label, "x");
+
+    let mut builder = MyStruct::builder_mut();
+    builder.id("id").version(3.5);
+
+    let my_struct = builder.build().unwrap();
+
+    assert_eq!(my_struct.id, "id");
+    assert_eq!(my_struct.version, Some("3.5".to_string()));
+    assert_eq!(*my_struct.label, "");
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "trace")]
+
+use std::sync::Mutex;
+
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct CapturingLogger;
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Debug
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            MESSAGES.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(trace)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(each = "arg")]
+    opt1: Option<Vec<usize>>,
+
+    #[builder(default)]
+    def1: usize,
+}
+
+#[test]
+fn trace() {
+    log::set_logger(&CapturingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let my_struct = MyStruct::builder().def1(3).arg(2).req1(1).build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.opt1, Some(vec![2]));
+    assert_eq!(my_struct.def1, 3);
+
+    assert_eq!(
+        *MESSAGES.lock().unwrap(),
+        vec![
+            "MyStructBuilder: set `def1`",
+            "MyStructBuilder: set `opt1`",
+            "MyStructBuilder: set `req1`",
+        ]
+    );
+}
@@ -1,5 +1,12 @@
 #[test]
 fn ui_tests() {
     let t = trybuild::TestCases::new();
-    t.compile_fail("tests/ui/**/*.rs");
+    t.compile_fail("tests/ui/*.rs");
+    t.compile_fail("tests/ui/error/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+
+    // These only fail without the features of the crate.
+    if !cfg!(feature = "trace") {
+        t.compile_fail("tests/ui/feature/*.rs");
+    }
 }
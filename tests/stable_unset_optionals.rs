@@ -0,0 +1,23 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+    opt1: Option<usize>,
+    opt2: Option<Vec<usize>>,
+
+    #[builder(default)]
+    def1: usize,
+}
+
+#[test]
+fn unset_optionals() {
+    let my_struct = MyStruct::builder().req1(1).opt2(vec![]).build();
+
+    // The fields keep their types, so other derives see the unset fields as `None`.
+    let opt1: Option<usize> = my_struct.opt1;
+    let opt2: Option<Vec<usize>> = my_struct.opt2;
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(opt1, None);
+    assert_eq!(opt2, Some(vec![]));
+    assert_eq!(my_struct.def1, 0);
+}
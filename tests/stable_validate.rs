@@ -0,0 +1,90 @@
+fn positive(value: &usize) -> Result<(), &'static str> {
+    if *value == 0 {
+        Err("must be positive")
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(both_modes)]
+struct Form {
+    #[builder(check = positive)]
+    age: usize,
+
+    #[builder(check = |email: &String| if email.is_empty() { Err("must not be empty") } else { Ok(()) })]
+    email: Option<String>,
+
+    // The default values are not checked by the setters.
+    #[builder(default, check = positive)]
+    seats: usize,
+
+    #[builder(default, check = |name: &String| if name.is_empty() { Err("must not be empty") } else { Ok(()) })]
+    name: String,
+}
+
+#[test]
+fn collects_all_errors() {
+    let builder = Form::builder();
+
+    // The values that are not set are not checked.
+    let errors = builder.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0],
+        FormBuilderError::Seats("must be positive")
+    ));
+    assert!(matches!(
+        errors[1],
+        FormBuilderError::Name("must not be empty")
+    ));
+
+    let builder = builder
+        .age(30)
+        .unwrap()
+        .email("me@example.com".to_string())
+        .unwrap()
+        .seats(2)
+        .unwrap();
+    let errors = builder.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "invalid `name`: must not be empty");
+
+    let builder = builder.name("me".to_string()).unwrap();
+    assert!(builder.validate().is_ok());
+    let form = builder.build();
+    assert_eq!(
+        (form.age, form.email.as_deref()),
+        (30, Some("me@example.com"))
+    );
+    assert_eq!((form.seats, form.name.as_str()), (2, "me"));
+}
+
+#[test]
+fn borrowing_builder() {
+    let mut builder = Form::builder_mut();
+    builder.seats(1).unwrap().name("me".to_string()).unwrap();
+
+    assert!(builder.validate().is_ok());
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit)]
+struct Slot {
+    #[builder(check = positive)]
+    size: usize,
+
+    #[builder(default = 1, check = positive)]
+    count: usize,
+}
+
+#[test]
+fn maybe_uninit_storage() {
+    assert!(Slot::builder().validate().is_ok());
+
+    let builder = Slot::builder().size(1).unwrap();
+    assert!(builder.validate().is_ok());
+
+    let slot = builder.build();
+    assert_eq!((slot.size, slot.count), (1, 1));
+}
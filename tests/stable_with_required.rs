@@ -0,0 +1,34 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct<T: Clone> {
+    req1: usize,
+    req2: T,
+
+    #[builder(per_index)]
+    coords: [i32; 2],
+
+    opt1: Option<String>,
+
+    #[builder(default = 3)]
+    def1: usize,
+}
+
+#[test]
+fn with_required() {
+    let my_struct = MyStructBuilder::with_required(1, "req2", [4, 5])
+        .opt1("opt1".to_string())
+        .build();
+
+    assert_eq!(my_struct.req1, 1);
+    assert_eq!(my_struct.req2, "req2");
+    assert_eq!(my_struct.coords, [4, 5]);
+    assert_eq!(my_struct.opt1, Some("opt1".to_string()));
+    assert_eq!(my_struct.def1, 3);
+
+    let my_struct = MyStructBuilder::with_required(1, 2, [0, 0])
+        .req1(6)
+        .def1(7)
+        .build();
+
+    assert_eq!(my_struct.req1, 6);
+    assert_eq!(my_struct.def1, 7);
+}
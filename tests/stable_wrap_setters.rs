@@ -0,0 +1,78 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(setter = wrap)]
+    req1: Box<usize>,
+
+    #[builder(setter = wrap)]
+    req2: Arc<String>,
+
+    #[builder(setter = wrap)]
+    opt1: Option<Arc<usize>>,
+
+    #[builder(default, setter = wrap)]
+    def1: Rc<usize>,
+
+    req3: Box<usize>,
+}
+
+#[test]
+fn wrap_setters() {
+    let my_struct = MyStruct::builder()
+        .req1(1)
+        .req2("req2".to_string())
+        .opt1(2)
+        .def1(3)
+        .req3(Box::new(4))
+        .build();
+
+    assert_eq!(*my_struct.req1, 1);
+    assert_eq!(*my_struct.req2, "req2");
+    assert_eq!(my_struct.opt1, Some(Arc::new(2)));
+    assert_eq!(*my_struct.def1, 3);
+    assert_eq!(*my_struct.req3, 4);
+}
+
+#[derive(tidy_builder::Builder)]
+struct Unsized {
+    #[builder(setter = wrap)]
+    name: Arc<str>,
+
+    #[builder(setter = wrap)]
+    bytes: Option<Box<[u8]>>,
+
+    #[builder(setter = wrap)]
+    path: std::sync::Arc<String>,
+
+    #[builder(default, setter = wrap)]
+    count: std::rc::Rc<usize>,
+}
+
+#[test]
+fn unsized_inner_types() {
+    let unsized_struct = Unsized::builder()
+        .name("name")
+        .bytes(vec![1, 2])
+        .path("path".to_string())
+        .build();
+
+    assert_eq!(&*unsized_struct.name, "name");
+    assert_eq!(unsized_struct.bytes.as_deref(), Some(&[1, 2][..]));
+    assert_eq!(*unsized_struct.path, "path");
+    assert_eq!(*unsized_struct.count, 0);
+}
+
+#[test]
+fn pointers_are_taken_as_is() {
+    let name: Arc<str> = Arc::from("shared");
+    let unsized_struct = Unsized::builder()
+        .name(name.clone())
+        .path(Arc::new("path".to_string()))
+        .count(Rc::new(2))
+        .build();
+
+    assert!(Arc::ptr_eq(&unsized_struct.name, &name));
+    assert_eq!(*unsized_struct.count, 2);
+}
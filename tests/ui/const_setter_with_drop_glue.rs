@@ -0,0 +1,14 @@
+#[derive(tidy_builder::Builder)]
+#[builder(const)]
+struct Labeled {
+    id: u32,
+    label: Option<String>,
+}
+
+const fn labeled(builder: LabeledBuilder<false>) -> LabeledBuilder<false> {
+    builder.label(String::new())
+}
+
+fn main() {
+    let _labeled = labeled(Labeled::builder()).id(1).build();
+}
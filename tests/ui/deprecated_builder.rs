@@ -0,0 +1,11 @@
+#![deny(deprecated)]
+
+#[derive(tidy_builder::Builder)]
+#[builder(deprecated = "use MyStruct::new instead")]
+struct MyStruct {
+    req1: usize,
+}
+
+fn main() {
+    let _ = MyStruct::builder().req1(1).build();
+}
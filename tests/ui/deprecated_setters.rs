@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(deprecated = "use req1 instead", name = "legacy")]
+    opt1: Option<usize>,
+}
+
+fn main() {
+    let _ = MyStruct::builder().req1(1).legacy(2).build();
+}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(each = "arg", check = |args: &Vec<String>| if args.is_empty() { Err("empty") } else { Ok(()) })]
+    args: Vec<String>,
+}
+
+fn main() {}
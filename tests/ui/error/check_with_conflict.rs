@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(check = |v: &u32| Ok::<_, &str>(()), check_with = |_, v: &u32| Ok::<_, &str>(()))]
+    value: u32,
+}
+
+fn main() {}
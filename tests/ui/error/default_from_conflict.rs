@@ -0,0 +1,9 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(default = 1, default_from = |s| s.req1)]
+    def1: usize,
+
+    req1: usize,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(value = 1)]
+    #[builder(value = 2)]
+    field: usize,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[derive(tidy_builder::Builder)]
+struct Node {
+    name: String,
+}
+
+#[derive(tidy_builder::Builder)]
+struct Tree {
+    #[builder(each_builder)]
+    children: Vec<Node>,
+}
+
+fn main() {}
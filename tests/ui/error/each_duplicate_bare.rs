@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(each, each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(each = "count")]
+    counts: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[derive(tidy_builder::Builder)]
+struct Inner {
+    value: usize,
+}
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(flatten)]
+    inner: Option<Inner>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(tidy_builder::Builder)]
+#[builder(from_pairs)]
+struct MyStruct {
+    req1: String,
+    opt1: Option<usize>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+#[builder(storage = maybe_uninit, both_modes)]
+struct MyStruct {
+    req1: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use std::collections::BTreeSet;
+
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(each = "tag", on_push = |tag: &u8| println!("{tag}"))]
+    tags: BTreeSet<u8>,
+}
+
+fn main() {}
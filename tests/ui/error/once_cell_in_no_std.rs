@@ -0,0 +1,12 @@
+fn default_names() -> Vec<String> {
+    vec![]
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(no_std)]
+struct MyStruct {
+    #[builder(default = default_names, once_cell)]
+    names: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(once_cell)]
+    names: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(once, setter = set_if_some)]
+    port: Option<u16>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct<const N: usize> {
+    #[builder(per_index)]
+    coords: [f64; N],
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(required)]
+    field: usize,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+#[builder(mode = runtime, storage = maybe_uninit)]
+struct MyStruct {
+    req1: usize,
+}
+
+fn main() {}
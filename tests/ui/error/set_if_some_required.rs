@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(setter = set_if_some)]
+    req1: usize,
+}
+
+fn main() {}
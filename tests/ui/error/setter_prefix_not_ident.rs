@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+#[builder(setter_prefix = "with-")]
+struct MyStruct {
+    req1: usize,
+}
+
+fn main() {}
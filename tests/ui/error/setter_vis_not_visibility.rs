@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(vis = "crate-wide")]
+    req1: usize,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[derive(tidy_builder::Builder)]
+#[builder(state_enum)]
+struct MyStruct {
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+
+    #[builder(once)]
+    g: Option<usize>,
+}
+
+fn main() {}
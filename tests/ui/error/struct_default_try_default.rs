@@ -0,0 +1,8 @@
+#[derive(tidy_builder::Builder)]
+#[builder(default)]
+struct MyStruct {
+    #[builder(try_default = "8080".parse::<u16>())]
+    port: u16,
+}
+
+fn main() {}
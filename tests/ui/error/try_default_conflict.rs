@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(default = 1, try_default = "1".parse::<usize>())]
+    field: usize,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+#[builder(derive(Debug))]
+struct MyStruct {
+    args: Vec<String>,
+}
+
+fn main() {}
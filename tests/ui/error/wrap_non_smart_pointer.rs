@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(setter = wrap)]
+    req1: Vec<usize>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(tidy_builder::Builder)]
+#[builder(trace)]
+struct MyStruct {
+    req1: usize,
+}
+
+fn main() {}
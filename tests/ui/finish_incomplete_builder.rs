@@ -0,0 +1,13 @@
+#[derive(tidy_builder::Builder)]
+pub struct MyStruct {
+    req1: usize,
+    req2: usize,
+}
+
+fn finish(builder: impl MyStructBuilderComplete) {
+    let _built = builder.build();
+}
+
+fn main() {
+    finish(MyStruct::builder().req1(1));
+}
@@ -0,0 +1,14 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    #[builder(once, each = "tag")]
+    tags: Option<Vec<String>>,
+}
+
+fn main() {
+    let _ = MyStruct::builder()
+        .tag("a".to_string())
+        .tags(vec![])
+        .tag("b".to_string())
+        .tags(vec![])
+        .build();
+}
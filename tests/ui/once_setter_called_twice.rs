@@ -0,0 +1,11 @@
+#[derive(tidy_builder::Builder)]
+struct MyStruct {
+    req1: usize,
+
+    #[builder(once)]
+    opt1: Option<usize>,
+}
+
+fn main() {
+    let _ = MyStruct::builder().opt1(1).req1(1).opt1(2).build();
+}
@@ -0,0 +1,114 @@
+// The builders of `no_std` structs must not name the `std` crate, which doesn't resolve here.
+// It's still linked under another name to provide the runtime of the binary.
+#![no_std]
+
+extern crate alloc;
+extern crate std as runtime;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConfigError {
+    Empty,
+}
+
+fn nonempty(name: &String) -> Result<(), ConfigError> {
+    if name.is_empty() {
+        Err(ConfigError::Empty)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, tidy_builder::Builder)]
+#[builder(
+    no_std,
+    derive(Clone),
+    struct_debug,
+    into_builder,
+    partial,
+    setters_trait,
+    state_enum,
+    str_setters,
+    error = "ConfigError"
+)]
+struct Config {
+    #[builder(check = nonempty, check_error = "ConfigError")]
+    name: String,
+
+    #[builder(to_string)]
+    label: String,
+
+    port: Option<u16>,
+
+    #[builder(once)]
+    user: Option<String>,
+
+    #[builder(try_default = Ok::<_, ConfigError>(3))]
+    retries: u8,
+
+    #[builder(default, setter = wrap)]
+    shared: Box<[u8]>,
+
+    #[builder(default, each = "tag")]
+    tags: Vec<String>,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(no_std, both_modes)]
+struct Command {
+    #[builder(check = nonempty, check_error = "ConfigError")]
+    program: String,
+
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(tidy_builder::Builder)]
+#[builder(no_std, from_pairs)]
+struct Pairs {
+    first: u32,
+    second: Option<u32>,
+}
+
+#[derive(Debug, tidy_builder::Builder)]
+#[builder(no_std, mode = runtime)]
+struct Runtime {
+    value: u32,
+    extra: Option<u32>,
+}
+
+fn main() {
+    let builder = Config::builder()
+        .unwrap()
+        .name("server")
+        .unwrap()
+        .label(8)
+        .port(80)
+        .user("admin")
+        .shared(alloc::vec![1, 2])
+        .tag("a".to_string());
+    let _partial = builder.partial();
+    let _state = builder.clone().into_state();
+    let _kept = builder.build_keep();
+    let _boxed = builder.clone().build_boxed();
+    let _many = builder.clone().build_n(2);
+    let mut slot = MaybeUninit::uninit();
+    let _written = builder.clone().build_into(&mut slot);
+    let config = builder.build();
+    let _debug = alloc::format!("{:?}", config);
+    let _rebuilt = ConfigBuilder::from(config).build();
+
+    let mut builder = Command::builder_mut();
+    builder.program("ls".to_string()).unwrap();
+    builder.arg("-l".to_string());
+    let _command = builder.build().unwrap();
+
+    let _pairs = Pairs::from_pairs([("first", 1), ("second", 2)]).unwrap();
+    let _pairs_error = Pairs::from_pairs([("third", 3)]).err().unwrap().to_string();
+
+    let _runtime = Runtime::builder().value(1).try_build().unwrap();
+}
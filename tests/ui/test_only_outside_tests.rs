@@ -0,0 +1,10 @@
+#[derive(tidy_builder::Builder)]
+#[builder(test_only)]
+pub struct MyStruct {
+    req1: usize,
+    opt1: Option<usize>,
+}
+
+fn main() {
+    let _my_struct = MyStruct::builder().req1(1).opt1(2).build();
+}